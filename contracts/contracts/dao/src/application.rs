@@ -0,0 +1,104 @@
+/// Module for membership applications.
+/// Non-members apply on their own and an existing member sponsors the application
+/// by turning it into an add member proposal. Unsponsored applications expire.
+use soroban_sdk::{Address, BytesN, Env, String};
+
+use crate::event;
+use crate::member;
+use crate::metadata;
+use crate::pause;
+use crate::proposal;
+use crate::ttl;
+use crate::types;
+use crate::types::data_key;
+
+/// Submits an application for membership.
+///
+/// # Arguments
+///
+/// - `applicant` - The address applying for membership.
+/// - `statement` - Why the applicant wants to join.
+pub fn apply(env: &Env, applicant: Address, statement: String) {
+    pause::only_unpaused(env);
+
+    if member::is_member(env, applicant.clone()) {
+        panic!("Already a member");
+    }
+
+    if let Some(application) = read(env, applicant.clone()) {
+        if !is_expired(env, &application) {
+            panic!("Application already pending");
+        }
+    }
+
+    let submitted_at = env.ledger().timestamp();
+    let expires_at = submitted_at + metadata::dao::read(env).application_duration;
+
+    ttl::set(
+        env,
+        &data_key::Dao::Application(applicant.clone()),
+        &types::member::Application {
+            applicant: applicant.clone(),
+            statement,
+            submitted_at,
+            expires_at,
+        },
+    );
+
+    event::apply(env, applicant, expires_at);
+}
+
+/// Sponsors a pending application by creating an add member proposal for the applicant.
+/// The sponsor is the proposer of the proposal. Returns the id of the proposal.
+///
+/// # Arguments
+///
+/// - `applicant` - The applicant to sponsor.
+/// - `proposal_metadata` - The metadata of the add member proposal.
+pub fn sponsor(
+    env: &Env,
+    applicant: Address,
+    proposal_metadata: types::proposal::Metadata,
+) -> BytesN<32> {
+    let application = if let Some(application) = read(env, applicant.clone()) {
+        application
+    } else {
+        panic!("Application not found");
+    };
+
+    if is_expired(env, &application) {
+        panic!("Application has expired");
+    }
+
+    let sponsor = proposal_metadata.proposer.clone();
+
+    let proposal_id = proposal::add_member::write(
+        env,
+        types::proposal::AddMember {
+            metadata: proposal_metadata,
+            candidate: applicant.clone(),
+        },
+    );
+
+    env.storage()
+        .persistent()
+        .remove(&data_key::Dao::Application(applicant.clone()));
+
+    event::sponsor(env, applicant, sponsor, proposal_id.clone());
+
+    proposal_id
+}
+
+/// Reads the application of an applicant, including expired applications.
+///
+/// # Arguments
+///
+/// - `applicant` - The applicant.
+pub fn read(env: &Env, applicant: Address) -> Option<types::member::Application> {
+    ttl::get(env, &data_key::Dao::Application(applicant))
+}
+
+/// Checks if an application is past its expiry without a sponsor.
+pub fn is_expired(env: &Env, application: &types::member::Application) -> bool {
+    application.expires_at < env.ledger().timestamp()
+}
@@ -1,11 +1,10 @@
+use crate::ttl;
 use crate::types::data_key;
-use soroban_sdk::{Address, Env, IntoVal, Symbol};
+use soroban_sdk::{Address, Env, IntoVal, Symbol, Vec};
 
 fn get_dao_token_contract_id(env: &Env) -> Address {
-    if let Some(dao_token_contract_id) = env
-        .storage()
-        .persistent()
-        .get::<_, Address>(&data_key::Dao::TokenContractId)
+    if let Some(dao_token_contract_id) =
+        ttl::get::<_, Address>(env, &data_key::Dao::TokenContractId)
     {
         dao_token_contract_id
     } else {
@@ -59,3 +58,44 @@ pub fn balance(env: &Env, account: Address) -> i128 {
         (&account,).into_val(env),
     )
 }
+
+/// Returns the total supply of the DAO token contract which grants voting power.
+pub fn total_supply(env: &Env) -> i128 {
+    let dao_token_contract_id = get_dao_token_contract_id(env);
+
+    env.invoke_contract::<i128>(
+        &dao_token_contract_id,
+        &Symbol::new(env, "total_supply"),
+        ().into_val(env),
+    )
+}
+
+/// Adds the balances of legacy members, minted before the total supply was tracked, to it.
+///
+/// # Arguments
+///
+/// - `amount` - The amount of tokens held by the legacy members.
+pub fn migrate_supply(env: &Env, amount: i128) {
+    let dao_token_contract_id = get_dao_token_contract_id(env);
+
+    env.invoke_contract::<()>(
+        &dao_token_contract_id,
+        &Symbol::new(env, "migrate_supply"),
+        (amount,).into_val(env),
+    );
+}
+
+/// Extends the TTL of the DAO token contract and of the balances of some accounts.
+///
+/// # Arguments
+///
+/// - `accounts` - The accounts whose balances are extended.
+pub fn extend_ttl(env: &Env, accounts: Vec<Address>) {
+    let dao_token_contract_id = get_dao_token_contract_id(env);
+
+    env.invoke_contract::<()>(
+        &dao_token_contract_id,
+        &Symbol::new(env, "extend_ttl"),
+        (accounts,).into_val(env),
+    );
+}
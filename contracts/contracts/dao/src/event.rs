@@ -1,5 +1,10 @@
 /// Module for emitting events
-use soroban_sdk::{Address, BytesN, Env, Symbol};
+use crate::types;
+use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
+
+/// The version of the proposal lifecycle events, their second topic.
+/// It is increased whenever the topics or data of these events change.
+pub const LIFECYCLE_VERSION: u32 = 1;
 
 pub fn add_member(env: &Env, who: Address) {
     let topics = (Symbol::new(env, "add_member"), who.clone());
@@ -11,6 +16,16 @@ pub fn remove_member(env: &Env, who: Address) {
     env.events().publish(topics, who);
 }
 
+pub fn left(env: &Env, who: Address) {
+    let topics = (Symbol::new(env, "left"), who.clone());
+    env.events().publish(topics, who);
+}
+
+pub fn ragequit(env: &Env, who: Address, proposal_id: BytesN<32>) {
+    let topics = (Symbol::new(env, "ragequit"), who);
+    env.events().publish(topics, proposal_id);
+}
+
 pub fn grant_vote_power(env: &Env, who: Address) {
     let topics = (Symbol::new(env, "grant_vote_power"), who.clone());
     env.events().publish(topics, who);
@@ -26,7 +41,204 @@ pub fn vote(env: &Env, proposal_id: BytesN<32>, voter: Address) {
     env.events().publish(topics, voter);
 }
 
+pub fn object(env: &Env, proposal_id: BytesN<32>, objector: Address) {
+    let topics = (Symbol::new(env, "object"), proposal_id.clone());
+    env.events().publish(topics, objector);
+}
+
+pub fn signal(env: &Env, proposal_id: BytesN<32>, voter: Address) {
+    let topics = (Symbol::new(env, "signal"), proposal_id.clone());
+    env.events().publish(topics, voter);
+}
+
+pub fn temperature_check_passed(env: &Env, proposal_id: BytesN<32>, signals: u32) {
+    let topics = (Symbol::new(env, "temperature_check_passed"), proposal_id);
+    env.events().publish(topics, signals);
+}
+
+pub fn commit(env: &Env, proposal_id: BytesN<32>, voter: Address) {
+    let topics = (Symbol::new(env, "commit"), proposal_id.clone());
+    env.events().publish(topics, voter);
+}
+
+pub fn reveal(env: &Env, proposal_id: BytesN<32>, voter: Address) {
+    let topics = (Symbol::new(env, "reveal"), proposal_id.clone());
+    env.events().publish(topics, voter);
+}
+
 pub fn evaluate(env: &Env, proposal_id: BytesN<32>, evaluator: Address) {
     let topics = (Symbol::new(env, "evaluate"), evaluator);
     env.events().publish(topics, proposal_id);
 }
+
+pub fn early_resolution(env: &Env, proposal_id: BytesN<32>, accepted: bool) {
+    let topics = (Symbol::new(env, "early_resolution"), proposal_id);
+    env.events().publish(topics, accepted);
+}
+
+pub fn veto(env: &Env, proposal_id: BytesN<32>, guardian: Address) {
+    let topics = (Symbol::new(env, "veto"), proposal_id);
+    env.events().publish(topics, guardian);
+}
+
+pub fn set_guardians(env: &Env, guardians: Vec<Address>) {
+    let topics = (Symbol::new(env, "set_guardians"),);
+    env.events().publish(topics, guardians);
+}
+
+pub fn paused(env: &Env, who: Address, paused_until: u64) {
+    let topics = (Symbol::new(env, "paused"), who);
+    env.events().publish(topics, paused_until);
+}
+
+pub fn unpaused(env: &Env, who: Address) {
+    let topics = (Symbol::new(env, "unpaused"), who.clone());
+    env.events().publish(topics, who);
+}
+
+pub fn grant_role(env: &Env, who: Address, role: Symbol) {
+    let topics = (Symbol::new(env, "grant_role"), who);
+    env.events().publish(topics, role);
+}
+
+pub fn revoke_role(env: &Env, who: Address, role: Symbol) {
+    let topics = (Symbol::new(env, "revoke_role"), who);
+    env.events().publish(topics, role);
+}
+
+pub fn set_permission(env: &Env, permission: types::role::Permission) {
+    let topics = (
+        Symbol::new(env, "set_permission"),
+        permission.proposal_type,
+        permission.action,
+    );
+    env.events().publish(topics, permission.roles);
+}
+
+pub fn update_profile(env: &Env, who: Address, profile_hash: BytesN<32>) {
+    let topics = (Symbol::new(env, "update_profile"), who);
+    env.events().publish(topics, profile_hash);
+}
+
+pub fn spend_accepted(env: &Env, proposal_id: BytesN<32>, grace_end: u64) {
+    let topics = (Symbol::new(env, "spend_accepted"), proposal_id);
+    env.events().publish(topics, grace_end);
+}
+
+pub fn execute(env: &Env, proposal_id: BytesN<32>) {
+    let topics = (Symbol::new(env, "execute"), proposal_id.clone());
+    env.events().publish(topics, proposal_id);
+}
+
+pub fn apply(env: &Env, applicant: Address, expires_at: u64) {
+    let topics = (Symbol::new(env, "apply"), applicant);
+    env.events().publish(topics, expires_at);
+}
+
+pub fn sponsor(env: &Env, applicant: Address, sponsor: Address, proposal_id: BytesN<32>) {
+    let topics = (Symbol::new(env, "sponsor"), applicant, sponsor);
+    env.events().publish(topics, proposal_id);
+}
+
+pub fn awaiting_consent(env: &Env, proposal_id: BytesN<32>, consent_end: u64) {
+    let topics = (Symbol::new(env, "awaiting_consent"), proposal_id);
+    env.events().publish(topics, consent_end);
+}
+
+pub fn consent(env: &Env, proposal_id: BytesN<32>, candidate: Address) {
+    let topics = (Symbol::new(env, "consent"), proposal_id);
+    env.events().publish(topics, candidate);
+}
+
+pub fn prune(env: &Env, proposal_id: BytesN<32>, body_hash: BytesN<32>) {
+    let topics = (Symbol::new(env, "prune"), proposal_id);
+    env.events().publish(topics, body_hash);
+}
+
+/// Emits a lifecycle event when a proposal is created.
+///
+/// - topics - `["proposal_created", version: u32, proposal_id: BytesN<32>]`
+/// - data - `[proposal_type: Type, proposer: Address, start_time: u64, end_time: u64]`
+pub fn proposal_created(
+    env: &Env,
+    proposal_id: BytesN<32>,
+    proposal_type: types::proposal::Type,
+    proposal_metadata: &types::proposal::Metadata,
+) {
+    let topics = (
+        Symbol::new(env, "proposal_created"),
+        LIFECYCLE_VERSION,
+        proposal_id,
+    );
+    env.events().publish(
+        topics,
+        (
+            proposal_type,
+            proposal_metadata.proposer.clone(),
+            proposal_metadata.start_time,
+            proposal_metadata.end_time,
+        ),
+    );
+}
+
+/// Emits a lifecycle event when a status is stored for a proposal.
+/// An accepted proposal lapses without a transaction when its consent window ends, so no event is
+/// emitted for it; the end of the window is published by the `awaiting_consent` event.
+/// A proposal whose candidates are no longer valid when they consent lapses with an event.
+///
+/// - topics - `["status_changed", version: u32, proposal_id: BytesN<32>]`
+/// - data - `[old_status: Status, new_status: Status]`
+pub fn status_changed(
+    env: &Env,
+    proposal_id: BytesN<32>,
+    old_status: types::proposal::Status,
+    new_status: types::proposal::Status,
+) {
+    let topics = (
+        Symbol::new(env, "status_changed"),
+        LIFECYCLE_VERSION,
+        proposal_id,
+    );
+    env.events().publish(topics, (old_status, new_status));
+}
+
+/// Emits a lifecycle event when a proposal is accepted, rejected or vetoed.
+///
+/// - topics - `["proposal_finalized", version: u32, proposal_id: BytesN<32>]`
+/// - data - `[status: Status, tally: u32, votes: u32, objections: u32]`
+pub fn proposal_finalized(
+    env: &Env,
+    proposal_id: BytesN<32>,
+    status: types::proposal::Status,
+    tally: u32,
+    votes: u32,
+    objections: u32,
+) {
+    let topics = (
+        Symbol::new(env, "proposal_finalized"),
+        LIFECYCLE_VERSION,
+        proposal_id,
+    );
+    env.events()
+        .publish(topics, (status, tally, votes, objections));
+}
+
+pub fn bond_refunded(env: &Env, proposal_id: BytesN<32>, proposer: Address, amount: i128) {
+    let topics = (Symbol::new(env, "bond_refunded"), proposal_id, proposer);
+    env.events().publish(topics, amount);
+}
+
+pub fn bond_forfeited(env: &Env, proposal_id: BytesN<32>, amount: i128) {
+    let topics = (Symbol::new(env, "bond_forfeited"), proposal_id);
+    env.events().publish(topics, amount);
+}
+
+pub fn amended(
+    env: &Env,
+    proposal_id: BytesN<32>,
+    previous_hash: BytesN<32>,
+    body_hash: BytesN<32>,
+) {
+    let topics = (Symbol::new(env, "amended"), proposal_id);
+    env.events().publish(topics, (previous_hash, body_hash));
+}
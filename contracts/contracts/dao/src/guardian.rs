@@ -0,0 +1,25 @@
+/// Module for the guardians of the DAO.
+/// Guardians are a safety net against captured votes and can veto proposals.
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::ttl;
+use crate::types::data_key;
+
+/// Replaces the guardians of the DAO. An empty list removes all guardians.
+///
+/// # Arguments
+///
+/// - `guardians` - The new guardians.
+pub fn write_guardians(env: &Env, guardians: Vec<Address>) {
+    ttl::set(env, &data_key::Dao::Guardians, &guardians);
+}
+
+/// Reads the guardians of the DAO.
+pub fn read_guardians(env: &Env) -> Vec<Address> {
+    ttl::get(env, &data_key::Dao::Guardians).unwrap_or(Vec::new(env))
+}
+
+/// Checks if an address is a guardian of the DAO.
+pub fn is_guardian(env: &Env, guardian: Address) -> bool {
+    read_guardians(env).contains(&guardian)
+}
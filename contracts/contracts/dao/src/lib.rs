@@ -1,5 +1,7 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, IntoVal, String, Symbol, Vec};
+use soroban_sdk::{
+    contract, contractimpl, vec, Address, BytesN, Env, IntoVal, String, Symbol, Vec,
+};
 
 pub mod types;
 pub use types::*;
@@ -9,308 +11,1190 @@ pub mod metadata;
 pub mod member;
 pub use member::*;
 
+pub mod guardian;
+
+pub mod role;
+
+pub mod pause;
+
+pub mod application;
+
 mod mock;
 
-mod event;
+mod event;
+
+mod ttl;
+
+pub mod proposal;
+
+mod dao_token;
+
+#[contract]
+pub struct DaoContract;
+
+#[contractimpl]
+impl DaoContract {
+    /// Initializes the DAO.
+    ///
+    /// This function should only be called once.
+    ///
+    /// # Arguments
+    ///
+    /// - `initial_members` - The initial members of the DAO. Must be atleast 3.
+    /// - `dao_token_wasm_hash` - The WASM hash of the DAO token contract.
+    /// - `metadata` - The metadata for the DAO.
+    pub fn initialize(
+        env: Env,
+        initial_members: Vec<Address>,
+        dao_token_wasm_hash: BytesN<32>,
+        metadata: dao::Metadata,
+    ) {
+        if initial_members.len() < MIN_MEMBERS {
+            panic!("Initial members must be atleast 3");
+        }
+
+        if metadata.min_members == 0 || metadata.min_members > initial_members.len() {
+            panic!("min_members must be between 1 and the number of initial members");
+        }
+
+        if metadata.min_voting_members > initial_members.len() {
+            panic!("min_voting_members must not exceed the number of initial members");
+        }
+
+        if metadata.ttl_threshold > metadata.ttl_extend_to {
+            panic!("ttl_threshold must not exceed ttl_extend_to");
+        }
+
+        if metadata.ttl_extend_to > env.storage().max_ttl() {
+            panic!("ttl_extend_to must not exceed the maximum TTL");
+        }
+
+        if let dao::Bond::Asset(_, amount) = &metadata.proposal_bond {
+            if *amount <= 0 {
+                panic!("Bond amount must be greater than zero");
+            }
+        }
+
+        if metadata.voting_mode == types::proposal::VotingMode::DaoDefault {
+            panic!("DAO voting mode must be Simple or Quadratic");
+        }
+
+        let dao_token_contract_id = deploy_dao_token_contract(&env, dao_token_wasm_hash);
+
+        metadata::dao::write(&env, metadata);
+
+        ttl::set(
+            &env,
+            &data_key::Dao::TokenContractId,
+            &dao_token_contract_id,
+        );
+
+        add_members(&env, initial_members.clone());
+
+        for member in initial_members {
+            proposal::grant_voting_power::grant(&env, member.clone());
+            event::add_member(&env, member);
+        }
+    }
+
+    /// Returns a page of the members of the DAO.
+    ///
+    /// # Arguments
+    ///
+    /// - `offset` - The index of the first member to return.
+    /// - `limit` - The maximum number of members to return.
+    pub fn get_members(env: Env, offset: u32, limit: u32) -> Vec<Address> {
+        read_members(&env, offset, limit)
+    }
+
+    /// Leaves the DAO. The voting power of the member is burned.
+    /// The DAO must keep at least `min_members` members and `min_voting_members` voting members.
+    ///
+    /// # Arguments
+    ///
+    /// - `member` - The member leaving the DAO.
+    pub fn leave(env: Env, member: Address) {
+        member.require_auth();
+
+        exit(&env, member.clone());
+
+        event::left(&env, member);
+    }
+
+    /// Leaves the DAO with a pro rata share of every treasury asset, burning the voting power of the member.
+    /// Only allowed for members who did not vote for an accepted spend proposal, during its grace period.
+    /// The DAO must keep at least `min_members` members and `min_voting_members` voting members.
+    ///
+    /// # Arguments
+    ///
+    /// - `member` - The member leaving the DAO.
+    /// - `proposal_id` - The id of the accepted spend proposal.
+    pub fn ragequit(env: Env, member: Address, proposal_id: BytesN<32>) {
+        member.require_auth();
+
+        if !is_member(&env, member.clone()) {
+            panic!("Member does not exist");
+        }
+
+        proposal::spend::ragequit(&env, member.clone(), proposal_id.clone());
+
+        exit(&env, member.clone());
+
+        event::ragequit(&env, member, proposal_id);
+    }
+
+    /// Applies for membership of the DAO as a non-member.
+    /// The application expires unless a member sponsors it within `application_duration`.
+    ///
+    /// # Arguments
+    ///
+    /// - `applicant` - The address applying for membership.
+    /// - `statement` - Why the applicant wants to join.
+    pub fn apply_for_membership(env: Env, applicant: Address, statement: String) {
+        applicant.require_auth();
+
+        application::apply(&env, applicant, statement);
+    }
+
+    /// Sponsors a pending membership application, turning it into an add member proposal
+    /// created by the sponsor.
+    ///
+    /// # Arguments
+    ///
+    /// - `applicant` - The applicant to sponsor.
+    /// - `metadata` - The metadata of the add member proposal, the proposer is the sponsor.
+    pub fn sponsor_application(
+        env: Env,
+        applicant: Address,
+        metadata: types::proposal::Metadata,
+    ) -> BytesN<32> {
+        metadata.proposer.require_auth();
+
+        application::sponsor(&env, applicant, metadata)
+    }
+
+    /// Returns the membership application of an applicant.
+    ///
+    /// # Arguments
+    ///
+    /// - `applicant` - The applicant.
+    pub fn get_application(env: Env, applicant: Address) -> types::member::Application {
+        if let Some(application) = application::read(&env, applicant) {
+            application
+        } else {
+            panic!("Application not found");
+        }
+    }
+
+    /// Returns the record of a member.
+    ///
+    /// # Arguments
+    ///
+    /// - `member` - The member.
+    pub fn get_member(env: Env, member: Address) -> types::member::Member {
+        read_member(&env, member)
+    }
+
+    /// Updates the display name and profile document hash of the calling member.
+    ///
+    /// # Arguments
+    ///
+    /// - `member` - The member updating their profile.
+    /// - `name` - The display name, empty to clear it.
+    /// - `profile_hash` - The sha256 hash of the profile document, all zeros to clear it.
+    pub fn update_member_profile(
+        env: Env,
+        member: Address,
+        name: String,
+        profile_hash: BytesN<32>,
+    ) {
+        member.require_auth();
+
+        update_profile(&env, member.clone(), name, profile_hash.clone());
+
+        event::update_profile(&env, member, profile_hash);
+    }
+
+    /// Returns the total number of members of the DAO.
+    pub fn get_member_count(env: Env) -> u32 {
+        total(&env)
+    }
+
+    /// Moves up to `limit` members from the legacy members vector into per-member storage.
+    /// Legacy members with voting power are counted as voters and their tokens are added to
+    /// the total supply. Returns the number of members still left to migrate.
+    ///
+    /// # Arguments
+    ///
+    /// - `limit` - The maximum number of members to migrate.
+    pub fn migrate_members(env: Env, limit: u32) -> u32 {
+        member::migrate(&env, limit)
+    }
+
+    /// Extends the TTL of the DAO-wide storage entries and of a page of members,
+    /// including their dao token balances, so they are not archived.
+    ///
+    /// # Arguments
+    ///
+    /// - `offset` - The index of the first member to extend.
+    /// - `limit` - The maximum number of members to extend.
+    pub fn extend_ttl(env: Env, offset: u32, limit: u32) {
+        ttl::extend_dao(&env, offset, limit);
+    }
+
+    /// Extends the TTL of the storage entries of a proposal and of a page of its votes,
+    /// objections and secret ballot commitments so they are not archived.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    /// - `offset` - The index of the first vote, objection and commitment to extend.
+    /// - `limit` - The maximum number of votes, objections and commitments to extend.
+    pub fn extend_proposal_ttl(env: Env, proposal_id: BytesN<32>, offset: u32, limit: u32) {
+        ttl::extend_proposal(&env, proposal_id, offset, limit);
+    }
+
+    /// Prunes a proposal finalized more than the prune delay of the DAO ago.
+    /// Its body, metadata, status and votes are removed, only its summary is kept.
+    /// Up to `limit` votes, objections and commitments are removed per call, so a proposal
+    /// with many of them is pruned over several calls. Returns the number still left to remove.
+    /// Anyone may prune a proposal.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    /// - `limit` - The maximum number of votes, objections and commitments to remove.
+    pub fn prune_proposal(env: Env, proposal_id: BytesN<32>, limit: u32) -> u32 {
+        proposal::prune(&env, proposal_id, limit)
+    }
+
+    /// Returns the summary of a proposal, which stays available after it is pruned.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    pub fn get_proposal_summary(env: Env, proposal_id: BytesN<32>) -> types::proposal::Summary {
+        proposal::read_summary(&env, proposal_id)
+    }
+
+    /// Returns the guardians of the DAO.
+    pub fn get_guardians(env: Env) -> Vec<Address> {
+        guardian::read_guardians(&env)
+    }
+
+    /// Returns the treasury assets which are paid out pro rata on ragequit.
+    pub fn get_treasury_assets(env: Env) -> Vec<Address> {
+        metadata::dao::read(&env).treasury_assets
+    }
+
+    /// Returns the roles of a member.
+    ///
+    /// # Arguments
+    ///
+    /// - `member` - The member.
+    pub fn get_member_roles(env: Env, member: Address) -> Vec<Symbol> {
+        role::read_roles(&env, member)
+    }
+
+    /// Returns the roles allowed to perform an action on a type of proposal.
+    /// No roles means every member is allowed.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_type` - The type of proposal.
+    /// - `action` - The action.
+    pub fn get_permission(
+        env: Env,
+        proposal_type: types::proposal::Type,
+        action: types::role::Action,
+    ) -> Vec<Symbol> {
+        role::read_permission(&env, proposal_type, action)
+    }
+
+    /// Pauses the DAO. A guardian pauses it right away, voting members vote for an emergency pause
+    /// which takes effect once the votes exceed `emergency_pause_percentage` of the voting members.
+    /// Votes expire after `max_pause_duration`, as does the pause itself. After a pause ended,
+    /// guardians cannot pause the DAO again for `pause_cooldown`, only members can vote for a pause.
+    ///
+    /// # Arguments
+    ///
+    /// - `caller` - The guardian or member pausing the DAO.
+    pub fn pause(env: Env, caller: Address) {
+        caller.require_auth();
+
+        pause::pause(&env, caller);
+    }
+
+    /// Unpauses the DAO. A guardian unpauses it right away, voting members vote to unpause
+    /// which takes effect once the votes exceed `emergency_pause_percentage` of the voting members.
+    ///
+    /// # Arguments
+    ///
+    /// - `caller` - The guardian or member unpausing the DAO.
+    pub fn unpause(env: Env, caller: Address) {
+        caller.require_auth();
+
+        pause::unpause(&env, caller);
+    }
+
+    /// Checks if the DAO is paused.
+    pub fn is_paused(env: Env) -> bool {
+        pause::is_paused(&env)
+    }
+
+    /// Returns the time until which the DAO is or was last paused.
+    pub fn get_paused_until(env: Env) -> u64 {
+        pause::read_paused_until(&env)
+    }
+
+    /// Checks if a member has voting power.
+    ///
+    /// # Arguments
+    ///
+    /// - `member` - The member to check.
+    pub fn has_voting_power(env: &Env, member: Address) -> bool {
+        has_voting_power(env, member)
+    }
+
+    /// Returns the nonce the next proposal of a proposer is created with.
+    /// The id of a proposal is the sha256 hash of the XDR encoded tuple
+    /// `(sha256(XDR encoded proposal), proposer, nonce)`.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposer` - The proposer.
+    pub fn get_proposal_nonce(env: Env, proposer: Address) -> u64 {
+        proposal::read_nonce(&env, proposer)
+    }
+
+    /// Amends the name and description or the candidate of a proposal before it starts.
+    /// Only the proposer can amend a proposal.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposer` - The proposer of the proposal.
+    /// - `proposal_id` - The id of the proposal.
+    /// - `amendment` - The change to the proposal.
+    pub fn amend_proposal(
+        env: Env,
+        proposer: Address,
+        proposal_id: BytesN<32>,
+        amendment: types::proposal::Amendment,
+    ) {
+        proposer.require_auth();
+
+        proposal::amend(&env, proposer, proposal_id, amendment);
+    }
+
+    /// Returns the revisions of a proposal, oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    pub fn get_proposal_revisions(
+        env: Env,
+        proposal_id: BytesN<32>,
+    ) -> Vec<types::proposal::Revision> {
+        proposal::read_revisions(&env, proposal_id)
+    }
+
+    /// Returns the number of open proposals of a member and the earliest time they may
+    /// create their next proposal.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposer` - The member.
+    pub fn get_proposer_status(env: Env, proposer: Address) -> types::proposal::ProposerStatus {
+        proposal::read_proposer_status(&env, proposer)
+    }
+
+    /// Returns the bond escrowed for a proposal until it is finalized.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    pub fn get_proposal_bond(env: Env, proposal_id: BytesN<32>) -> dao::Bond {
+        proposal::read_bond(&env, proposal_id)
+    }
+
+    /// Returns the proposals which must be accepted and applied before a proposal can be evaluated.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    pub fn get_proposal_dependencies(env: Env, proposal_id: BytesN<32>) -> Vec<BytesN<32>> {
+        proposal::read_dependencies(&env, proposal_id)
+    }
+
+    /// Returns the proposals which depend on a proposal.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    pub fn get_proposal_dependents(env: Env, proposal_id: BytesN<32>) -> Vec<BytesN<32>> {
+        proposal::read_dependents(&env, proposal_id)
+    }
+
+    /// Returns the status of a proposal.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    pub fn get_proposal_status(env: Env, proposal_id: BytesN<32>) -> types::proposal::Status {
+        proposal::read_status(&env, proposal_id)
+    }
+
+    /// Votes on a proposal.
+    ///
+    /// # Arguments
+    ///
+    /// - `voter` - The voter to vote on the proposal.
+    /// - `proposal_id` - The id of the proposal.
+    pub fn vote_proposal(env: Env, voter: Address, proposal_id: BytesN<32>) {
+        voter.require_auth();
+
+        proposal::vote(&env, voter, proposal_id);
+    }
+
+    /// Votes on a quadratic voting proposal by spending voice credits.
+    /// The vote counts as the square root of the credits spent.
+    ///
+    /// # Arguments
+    ///
+    /// - `voter` - The voter to vote on the proposal.
+    /// - `proposal_id` - The id of the proposal.
+    /// - `credits` - The voice credits to spend, at most the voter's budget for the proposal.
+    pub fn quadratic_vote_proposal(
+        env: Env,
+        voter: Address,
+        proposal_id: BytesN<32>,
+        credits: u32,
+    ) {
+        voter.require_auth();
+
+        proposal::quadratic_vote(&env, voter, proposal_id, credits);
+    }
+
+    /// Returns a page of the votes of a proposal.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    /// - `offset` - The index of the first vote to return.
+    /// - `limit` - The maximum number of votes to return.
+    pub fn get_proposal_votes(
+        env: Env,
+        proposal_id: BytesN<32>,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Address> {
+        proposal::check_votes_unsealed(&env, proposal_id.clone());
+
+        proposal::read_votes(&env, proposal_id, offset, limit)
+    }
+
+    /// Moves up to `limit` votes of a proposal from the legacy votes vector into per-vote storage.
+    /// Returns the number of votes still left to migrate.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    /// - `limit` - The maximum number of votes to migrate.
+    pub fn migrate_proposal_votes(env: Env, proposal_id: BytesN<32>, limit: u32) -> u32 {
+        proposal::migrate_votes(&env, proposal_id, limit)
+    }
+
+    /// Returns the effective votes of a proposal which are compared against the quorum.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    pub fn get_proposal_tally(env: Env, proposal_id: BytesN<32>) -> u32 {
+        proposal::check_votes_unsealed(&env, proposal_id.clone());
+
+        proposal::read_tally(&env, proposal_id)
+    }
+
+    /// Vetoes a proposal. Only callable by a guardian of the DAO.
+    /// Proposals that are not evaluated yet and accepted general purpose proposals can be vetoed.
+    /// Proposals to set the guardians cannot be vetoed.
+    ///
+    /// # Arguments
+    ///
+    /// - `guardian` - The guardian vetoing the proposal.
+    /// - `proposal_id` - The id of the proposal.
+    pub fn veto_proposal(env: Env, guardian: Address, proposal_id: BytesN<32>) {
+        guardian.require_auth();
+
+        proposal::veto(&env, guardian, proposal_id);
+    }
+
+    /// Objects to an optimistic proposal.
+    /// An optimistic proposal passes when it ends unless enough members object to it.
+    ///
+    /// # Arguments
+    ///
+    /// - `objector` - The member objecting to the proposal.
+    /// - `proposal_id` - The id of the proposal.
+    pub fn object_proposal(env: Env, objector: Address, proposal_id: BytesN<32>) {
+        objector.require_auth();
+
+        proposal::object(&env, objector, proposal_id);
+    }
+
+    /// Returns a page of the members who objected to an optimistic proposal.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    /// - `offset` - The index of the first objector to return.
+    /// - `limit` - The maximum number of objectors to return.
+    pub fn get_proposal_objections(
+        env: Env,
+        proposal_id: BytesN<32>,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Address> {
+        proposal::read_objections(&env, proposal_id, offset, limit)
+    }
+
+    /// Signals support for a proposal during its temperature check.
+    /// The proposal only opens for voting at its start time if enough voting members signalled.
+    ///
+    /// # Arguments
+    ///
+    /// - `voter` - The member signalling support.
+    /// - `proposal_id` - The id of the proposal.
+    pub fn signal_proposal(env: Env, voter: Address, proposal_id: BytesN<32>) {
+        voter.require_auth();
+
+        proposal::signal(&env, voter, proposal_id);
+    }
+
+    /// Returns the members who signalled support for a proposal during its temperature check.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    pub fn get_proposal_signals(env: Env, proposal_id: BytesN<32>) -> Vec<Address> {
+        proposal::read_signals(&env, proposal_id)
+    }
+
+    /// Commits a secret vote on a secret ballot proposal while it is active.
+    ///
+    /// # Arguments
+    ///
+    /// - `voter` - The voter to vote on the proposal.
+    /// - `proposal_id` - The id of the proposal.
+    /// - `commitment` - `sha256(choice || salt)` where `choice` is a single byte, `1` in favour and `0` against.
+    pub fn commit_vote_proposal(
+        env: Env,
+        voter: Address,
+        proposal_id: BytesN<32>,
+        commitment: BytesN<32>,
+    ) {
+        voter.require_auth();
+
+        proposal::commit_vote(&env, voter, proposal_id, commitment);
+    }
+
+    /// Reveals a secret vote during the reveal phase of a secret ballot proposal.
+    ///
+    /// # Arguments
+    ///
+    /// - `voter` - The voter who committed the vote.
+    /// - `proposal_id` - The id of the proposal.
+    /// - `choice` - The committed choice, `true` votes in favour of the proposal.
+    /// - `salt` - The salt used for the commitment.
+    pub fn reveal_vote_proposal(
+        env: Env,
+        voter: Address,
+        proposal_id: BytesN<32>,
+        choice: bool,
+        salt: BytesN<32>,
+    ) {
+        voter.require_auth();
+
+        proposal::reveal_vote(&env, voter, proposal_id, choice, salt);
+    }
+
+    /// Proposal to add a member to the DAO.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal` - The proposal.
+    pub fn add_member_proposal(env: Env, proposal: types::proposal::AddMember) -> BytesN<32> {
+        proposal.metadata.proposer.require_auth();
+
+        proposal::add_member::write(&env, proposal.clone())
+    }
+
+    /// Returns the proposal to add a member to the DAO.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    pub fn get_add_member_proposal(
+        env: Env,
+        proposal_id: BytesN<32>,
+    ) -> types::proposal::AddMember {
+        proposal::add_member::read(&env, proposal_id)
+    }
+
+    /// Evaluates the proposal to add a member to the DAO.
+    /// if `quorum` has been reached then the member is added to the DAO once they consent.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    pub fn evaluate_add_member_proposal(env: Env, evaluator: Address, proposal_id: BytesN<32>) {
+        proposal::only_member_evaluate(&env, evaluator.clone(), proposal_id.clone());
+
+        event::evaluate(&env, proposal_id.clone(), evaluator);
+
+        proposal::add_member::evaluate(&env, proposal_id);
+    }
+
+    /// Consents to an accepted add member or grant voting power proposal as its candidate.
+    /// The proposal only takes effect once the candidate consents within `consent_period`,
+    /// otherwise it lapses. It also lapses if the candidate is no longer valid, e.g. left the DAO
+    /// before a grant of voting power.
+    ///
+    /// # Arguments
+    ///
+    /// - `candidate` - The candidate of the proposal.
+    /// - `proposal_id` - The id of the proposal.
+    pub fn consent_proposal(env: Env, candidate: Address, proposal_id: BytesN<32>) {
+        candidate.require_auth();
+
+        proposal::consent(&env, candidate, proposal_id);
+    }
+
+    /// Proposal to remove a member from the DAO.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal` - The proposal.
+    pub fn remove_member_proposal(env: Env, proposal: types::proposal::RemoveMember) -> BytesN<32> {
+        proposal.metadata.proposer.require_auth();
+
+        proposal::remove_member::write(&env, proposal.clone())
+    }
+
+    /// Returns the proposal to remove a member from the DAO.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    pub fn get_remove_member_proposal(
+        env: Env,
+        proposal_id: BytesN<32>,
+    ) -> types::proposal::RemoveMember {
+        proposal::remove_member::read(&env, proposal_id)
+    }
+
+    /// Evaluates the proposal to remove a member from the DAO.
+    /// if `quorum` has been reached then the member is removed from the DAO.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    pub fn evaluate_remove_member_proposal(env: Env, evaluator: Address, proposal_id: BytesN<32>) {
+        proposal::only_member_evaluate(&env, evaluator.clone(), proposal_id.clone());
+
+        event::evaluate(&env, proposal_id.clone(), evaluator);
+
+        proposal::remove_member::evaluate(&env, proposal_id);
+    }
+
+    /// Proposal to grant voting power to a member.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal` - The proposal.
+    pub fn grant_voting_proposal(
+        env: Env,
+        proposal: types::proposal::GrantVotingPower,
+    ) -> BytesN<32> {
+        proposal.metadata.proposer.require_auth();
+
+        proposal::grant_voting_power::write(&env, proposal.clone())
+    }
+
+    /// Returns the proposal to grant voting power to a member.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    pub fn get_grant_voting_proposal(
+        env: Env,
+        proposal_id: BytesN<32>,
+    ) -> types::proposal::GrantVotingPower {
+        proposal::grant_voting_power::read(&env, proposal_id)
+    }
+
+    /// Evaluates the proposal to grant voting power to a member.
+    /// if `quorum` has been reached then the member is granted voting power once they consent.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    pub fn evaluate_grant_voting_proposal(env: Env, evaluator: Address, proposal_id: BytesN<32>) {
+        proposal::only_member_evaluate(&env, evaluator.clone(), proposal_id.clone());
+
+        event::evaluate(&env, proposal_id.clone(), evaluator);
+
+        proposal::grant_voting_power::evaluate(&env, proposal_id);
+    }
 
-pub mod proposal;
+    /// Proposal to revoke voting power to a member.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal` - The proposal.
+    pub fn revoke_voting_proposal(
+        env: Env,
+        proposal: types::proposal::RevokeVotingPower,
+    ) -> BytesN<32> {
+        proposal.metadata.proposer.require_auth();
 
-mod dao_token;
+        proposal::revoke_voting_power::write(&env, proposal.clone())
+    }
 
-#[contract]
-pub struct DaoContract;
+    /// Returns the proposal to revoke voting power to a member.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    pub fn get_revoke_voting_proposal(
+        env: Env,
+        proposal_id: BytesN<32>,
+    ) -> types::proposal::RevokeVotingPower {
+        proposal::revoke_voting_power::read(&env, proposal_id)
+    }
 
-#[contractimpl]
-impl DaoContract {
-    /// Initializes the DAO.
+    /// Evaluates the proposal to revoke voting power to a member.
+    /// if `quorum` has been reached then the member is revoked voting power.
     ///
-    /// This function should only be called once.
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    pub fn evaluate_revoke_voting_proposal(env: Env, evaluator: Address, proposal_id: BytesN<32>) {
+        proposal::only_member_evaluate(&env, evaluator.clone(), proposal_id.clone());
+
+        event::evaluate(&env, proposal_id.clone(), evaluator);
+
+        proposal::revoke_voting_power::evaluate(&env, proposal_id);
+    }
+
+    /// Proposal to create a general purpose proposal.
     ///
     /// # Arguments
     ///
-    /// - `initial_members` - The initial members of the DAO. Must be atleast 3.
-    /// - `dao_token_wasm_hash` - The WASM hash of the DAO token contract.
-    /// - `metadata` - The metadata for the DAO.
-    pub fn initialize(
+    /// - `proposal` - The proposal.
+    pub fn general_purpose_proposal(
         env: Env,
-        initial_members: Vec<Address>,
-        dao_token_wasm_hash: BytesN<32>,
-        metadata: dao::Metadata,
-    ) {
-        if initial_members.len() < 3 {
-            panic!("Initial members must be atleast 3");
-        }
+        proposal: types::proposal::GeneralPurpose,
+    ) -> BytesN<32> {
+        proposal.metadata.proposer.require_auth();
 
-        let dao_token_contract_id = deploy_dao_token_contract(&env, dao_token_wasm_hash);
+        proposal::general_purpose::write(&env, proposal.clone())
+    }
 
-        env.storage()
-            .persistent()
-            .set(&data_key::Dao::TokenContractId, &dao_token_contract_id);
+    /// Returns the proposal to create a general purpose proposal.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    pub fn get_gneral_purpose_proposal(
+        env: Env,
+        proposal_id: BytesN<32>,
+    ) -> types::proposal::GeneralPurpose {
+        proposal::general_purpose::read(&env, proposal_id)
+    }
 
-        metadata::dao::write(&env, metadata);
+    /// Evaluates the proposal to create a general purpose proposal.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    pub fn evaluate_general_proposal(env: Env, evaluator: Address, proposal_id: BytesN<32>) {
+        proposal::only_member_evaluate(&env, evaluator.clone(), proposal_id.clone());
 
-        add_members(&env, initial_members.clone());
+        event::evaluate(&env, proposal_id.clone(), evaluator);
 
-        for member in initial_members {
-            proposal::grant_voting_power::grant(&env, member.clone());
-            event::add_member(&env, member);
-        }
+        proposal::general_purpose::evaluate(&env, proposal_id);
     }
 
-    /// Returns the members of the DAO.
-    pub fn get_members(env: Env) -> Vec<Address> {
-        read_members(&env)
+    /// Proposal to replace the guardians of the DAO.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal` - The proposal.
+    pub fn set_guardians_proposal(env: Env, proposal: types::proposal::SetGuardians) -> BytesN<32> {
+        proposal.metadata.proposer.require_auth();
+
+        proposal::set_guardians::write(&env, proposal.clone())
     }
 
-    /// Checks if a member has voting power.
+    /// Returns the proposal to replace the guardians of the DAO.
     ///
     /// # Arguments
     ///
-    /// - `member` - The member to check.
-    pub fn has_voting_power(env: &Env, member: Address) -> bool {
-        has_voting_power(env, member)
+    /// - `proposal_id` - The id of the proposal.
+    pub fn get_set_guardians_proposal(
+        env: Env,
+        proposal_id: BytesN<32>,
+    ) -> types::proposal::SetGuardians {
+        proposal::set_guardians::read(&env, proposal_id)
     }
 
-    /// Returns the status of a proposal.
+    /// Evaluates the proposal to replace the guardians of the DAO.
+    /// if `quorum` has been reached then the guardians are replaced.
     ///
     /// # Arguments
     ///
     /// - `proposal_id` - The id of the proposal.
-    pub fn get_proposal_status(env: Env, proposal_id: BytesN<32>) -> types::proposal::Status {
-        proposal::read_status(&env, proposal_id)
+    pub fn evaluate_set_guardians_proposal(env: Env, evaluator: Address, proposal_id: BytesN<32>) {
+        proposal::only_member_evaluate(&env, evaluator.clone(), proposal_id.clone());
+
+        event::evaluate(&env, proposal_id.clone(), evaluator);
+
+        proposal::set_guardians::evaluate(&env, proposal_id);
     }
 
-    /// Votes on a proposal.
+    /// Proposal to grant a role to a member.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal` - The proposal.
+    pub fn grant_role_proposal(env: Env, proposal: types::proposal::GrantRole) -> BytesN<32> {
+        proposal.metadata.proposer.require_auth();
+
+        proposal::grant_role::write(&env, proposal.clone())
+    }
+
+    /// Returns the proposal to grant a role to a member.
     ///
     /// # Arguments
     ///
-    /// - `voter` - The voter to vote on the proposal.
     /// - `proposal_id` - The id of the proposal.
-    pub fn vote_proposal(env: Env, voter: Address, proposal_id: BytesN<32>) {
-        voter.require_auth();
+    pub fn get_grant_role_proposal(
+        env: Env,
+        proposal_id: BytesN<32>,
+    ) -> types::proposal::GrantRole {
+        proposal::grant_role::read(&env, proposal_id)
+    }
 
-        proposal::vote(&env, voter, proposal_id);
+    /// Evaluates the proposal to grant a role to a member.
+    /// if `quorum` has been reached then the role is granted.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    pub fn evaluate_grant_role_proposal(env: Env, evaluator: Address, proposal_id: BytesN<32>) {
+        proposal::only_member_evaluate(&env, evaluator.clone(), proposal_id.clone());
+
+        event::evaluate(&env, proposal_id.clone(), evaluator);
+
+        proposal::grant_role::evaluate(&env, proposal_id);
+    }
+
+    /// Proposal to revoke a role from a member.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal` - The proposal.
+    pub fn revoke_role_proposal(env: Env, proposal: types::proposal::RevokeRole) -> BytesN<32> {
+        proposal.metadata.proposer.require_auth();
+
+        proposal::revoke_role::write(&env, proposal.clone())
     }
 
-    /// Returns the votes of a proposal.
+    /// Returns the proposal to revoke a role from a member.
     ///
     /// # Arguments
     ///
     /// - `proposal_id` - The id of the proposal.
-    pub fn get_proposal_votes(env: Env, proposal_id: BytesN<32>) -> Vec<Address> {
-        proposal::read_votes(&env, proposal_id)
+    pub fn get_revoke_role_proposal(
+        env: Env,
+        proposal_id: BytesN<32>,
+    ) -> types::proposal::RevokeRole {
+        proposal::revoke_role::read(&env, proposal_id)
     }
 
-    /// Proposal to add a member to the DAO.
+    /// Evaluates the proposal to revoke a role from a member.
+    /// if `quorum` has been reached then the role is revoked.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    pub fn evaluate_revoke_role_proposal(env: Env, evaluator: Address, proposal_id: BytesN<32>) {
+        proposal::only_member_evaluate(&env, evaluator.clone(), proposal_id.clone());
+
+        event::evaluate(&env, proposal_id.clone(), evaluator);
+
+        proposal::revoke_role::evaluate(&env, proposal_id);
+    }
+
+    /// Proposal to set the roles allowed to perform an action on a type of proposal.
     ///
     /// # Arguments
     ///
     /// - `proposal` - The proposal.
-    pub fn add_member_proposal(env: Env, proposal: types::proposal::AddMember) -> BytesN<32> {
+    pub fn set_permission_proposal(
+        env: Env,
+        proposal: types::proposal::SetPermission,
+    ) -> BytesN<32> {
         proposal.metadata.proposer.require_auth();
 
-        proposal::add_member::write(&env, proposal.clone())
+        proposal::set_permission::write(&env, proposal.clone())
     }
 
-    /// Returns the proposal to add a member to the DAO.
+    /// Returns the proposal to set the roles allowed to perform an action on a type of proposal.
     ///
     /// # Arguments
     ///
     /// - `proposal_id` - The id of the proposal.
-    pub fn get_add_member_proposal(
+    pub fn get_set_permission_proposal(
         env: Env,
         proposal_id: BytesN<32>,
-    ) -> types::proposal::AddMember {
-        proposal::add_member::read(&env, proposal_id)
+    ) -> types::proposal::SetPermission {
+        proposal::set_permission::read(&env, proposal_id)
     }
 
-    /// Evaluates the proposal to add a member to the DAO.
-    /// if `quorum` has been reached then the member is added to the DAO.
+    /// Evaluates the proposal to set the roles allowed to perform an action on a type of proposal.
+    /// if `quorum` has been reached then the permission is replaced.
     ///
     /// # Arguments
     ///
     /// - `proposal_id` - The id of the proposal.
-    pub fn evaluate_add_member_proposal(env: Env, evaluator: Address, proposal_id: BytesN<32>) {
-        if !is_member(&env, evaluator.clone()) {
-            panic!("Evaluator is not a member");
-        }
+    pub fn evaluate_set_permission_proposal(env: Env, evaluator: Address, proposal_id: BytesN<32>) {
+        proposal::only_member_evaluate(&env, evaluator.clone(), proposal_id.clone());
 
         event::evaluate(&env, proposal_id.clone(), evaluator);
 
-        proposal::add_member::evaluate(&env, proposal_id);
+        proposal::set_permission::evaluate(&env, proposal_id);
     }
 
-    /// Proposal to remove a member from the DAO.
+    /// Proposal to spend a treasury asset.
     ///
     /// # Arguments
     ///
     /// - `proposal` - The proposal.
-    pub fn remove_member_proposal(env: Env, proposal: types::proposal::RemoveMember) -> BytesN<32> {
+    pub fn spend_proposal(env: Env, proposal: types::proposal::Spend) -> BytesN<32> {
         proposal.metadata.proposer.require_auth();
 
-        proposal::remove_member::write(&env, proposal.clone())
+        proposal::spend::write(&env, proposal.clone())
     }
 
-    /// Returns the proposal to remove a member from the DAO.
+    /// Returns the proposal to spend a treasury asset.
     ///
     /// # Arguments
     ///
     /// - `proposal_id` - The id of the proposal.
-    pub fn get_remove_member_proposal(
+    pub fn get_spend_proposal(env: Env, proposal_id: BytesN<32>) -> types::proposal::Spend {
+        proposal::spend::read(&env, proposal_id)
+    }
+
+    /// Evaluates the proposal to spend a treasury asset.
+    /// if `quorum` has been reached then the grace period of the spend starts.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    pub fn evaluate_spend_proposal(env: Env, evaluator: Address, proposal_id: BytesN<32>) {
+        proposal::only_member_evaluate(&env, evaluator.clone(), proposal_id.clone());
+
+        event::evaluate(&env, proposal_id.clone(), evaluator);
+
+        proposal::spend::evaluate(&env, proposal_id);
+    }
+
+    /// Executes an accepted spend proposal once its grace period has ended.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    pub fn execute_spend_proposal(env: Env, proposal_id: BytesN<32>) {
+        proposal::spend::execute(&env, proposal_id);
+    }
+
+    /// Proposal to add members to the DAO in one batch.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal` - The proposal.
+    pub fn batch_add_proposal(env: Env, proposal: types::proposal::BatchAddMember) -> BytesN<32> {
+        proposal.metadata.proposer.require_auth();
+
+        proposal::batch_add_member::write(&env, proposal.clone())
+    }
+
+    /// Returns the proposal to add members to the DAO in one batch.
+    ///
+    /// # Arguments
+    ///
+    /// - `proposal_id` - The id of the proposal.
+    pub fn get_batch_add_proposal(
         env: Env,
         proposal_id: BytesN<32>,
-    ) -> types::proposal::RemoveMember {
-        proposal::remove_member::read(&env, proposal_id)
+    ) -> types::proposal::BatchAddMember {
+        proposal::batch_add_member::read(&env, proposal_id)
     }
 
-    /// Evaluates the proposal to remove a member from the DAO.
-    /// if `quorum` has been reached then the member is removed from the DAO.
+    /// Evaluates the proposal to add members to the DAO in one batch.
+    /// if `quorum` has been reached then the members are added to the DAO once all of them consent.
     ///
     /// # Arguments
     ///
     /// - `proposal_id` - The id of the proposal.
-    pub fn evaluate_remove_member_proposal(env: Env, evaluator: Address, proposal_id: BytesN<32>) {
-        if !is_member(&env, evaluator.clone()) {
-            panic!("Evaluator is not a member");
-        }
+    pub fn evaluate_batch_add_proposal(env: Env, evaluator: Address, proposal_id: BytesN<32>) {
+        proposal::only_member_evaluate(&env, evaluator.clone(), proposal_id.clone());
 
         event::evaluate(&env, proposal_id.clone(), evaluator);
 
-        proposal::remove_member::evaluate(&env, proposal_id);
+        proposal::batch_add_member::evaluate(&env, proposal_id);
     }
 
-    /// Proposal to grant voting power to a member.
+    /// Proposal to remove members from the DAO in one batch.
     ///
     /// # Arguments
     ///
     /// - `proposal` - The proposal.
-    pub fn grant_voting_proposal(
+    pub fn batch_remove_proposal(
         env: Env,
-        proposal: types::proposal::GrantVotingPower,
+        proposal: types::proposal::BatchRemoveMember,
     ) -> BytesN<32> {
         proposal.metadata.proposer.require_auth();
 
-        if has_voting_power(&env, proposal.candidate.clone()) {
-            panic!("Already has voting power");
-        }
-
-        proposal::grant_voting_power::write(&env, proposal.clone())
+        proposal::batch_remove_member::write(&env, proposal.clone())
     }
 
-    /// Returns the proposal to grant voting power to a member.
+    /// Returns the proposal to remove members from the DAO in one batch.
     ///
     /// # Arguments
     ///
     /// - `proposal_id` - The id of the proposal.
-    pub fn get_grant_voting_proposal(
+    pub fn get_batch_remove_proposal(
         env: Env,
         proposal_id: BytesN<32>,
-    ) -> types::proposal::GrantVotingPower {
-        proposal::grant_voting_power::read(&env, proposal_id)
+    ) -> types::proposal::BatchRemoveMember {
+        proposal::batch_remove_member::read(&env, proposal_id)
     }
 
-    /// Evaluates the proposal to grant voting power to a member.
-    /// if `quorum` has been reached then the member is granted voting power.
+    /// Evaluates the proposal to remove members from the DAO in one batch.
+    /// if `quorum` has been reached then the members are removed from the DAO.
     ///
     /// # Arguments
     ///
     /// - `proposal_id` - The id of the proposal.
-    pub fn evaluate_grant_voting_proposal(env: Env, evaluator: Address, proposal_id: BytesN<32>) {
-        if !is_member(&env, evaluator.clone()) {
-            panic!("Evaluator is not a member");
-        }
+    pub fn evaluate_batch_remove_proposal(env: Env, evaluator: Address, proposal_id: BytesN<32>) {
+        proposal::only_member_evaluate(&env, evaluator.clone(), proposal_id.clone());
 
         event::evaluate(&env, proposal_id.clone(), evaluator);
 
-        proposal::grant_voting_power::evaluate(&env, proposal_id);
+        proposal::batch_remove_member::evaluate(&env, proposal_id);
     }
 
-    /// Proposal to revoke voting power to a member.
+    /// Proposal to grant voting power to members in one batch.
     ///
     /// # Arguments
     ///
     /// - `proposal` - The proposal.
-    pub fn revoke_voting_proposal(
+    pub fn batch_grant_proposal(
         env: Env,
-        proposal: types::proposal::RevokeVotingPower,
+        proposal: types::proposal::BatchGrantVotingPower,
     ) -> BytesN<32> {
         proposal.metadata.proposer.require_auth();
 
-        if !has_voting_power(&env, proposal.candidate.clone()) {
-            panic!("Already does not have voting power");
-        }
-
-        proposal::revoke_voting_power::write(&env, proposal.clone())
+        proposal::batch_grant_voting_power::write(&env, proposal.clone())
     }
 
-    /// Returns the proposal to revoke voting power to a member.
+    /// Returns the proposal to grant voting power to members in one batch.
     ///
     /// # Arguments
     ///
     /// - `proposal_id` - The id of the proposal.
-    pub fn get_revoke_voting_proposal(
+    pub fn get_batch_grant_proposal(
         env: Env,
         proposal_id: BytesN<32>,
-    ) -> types::proposal::RevokeVotingPower {
-        proposal::revoke_voting_power::read(&env, proposal_id)
+    ) -> types::proposal::BatchGrantVotingPower {
+        proposal::batch_grant_voting_power::read(&env, proposal_id)
     }
 
-    /// Evaluates the proposal to revoke voting power to a member.
-    /// if `quorum` has been reached then the member is revoked voting power.
+    /// Evaluates the proposal to grant voting power to members in one batch.
+    /// if `quorum` has been reached then the members are granted voting power once all of them consent.
     ///
     /// # Arguments
     ///
     /// - `proposal_id` - The id of the proposal.
-    pub fn evaluate_revoke_voting_proposal(env: Env, evaluator: Address, proposal_id: BytesN<32>) {
-        if !is_member(&env, evaluator.clone()) {
-            panic!("Evaluator is not a member");
-        }
+    pub fn evaluate_batch_grant_proposal(env: Env, evaluator: Address, proposal_id: BytesN<32>) {
+        proposal::only_member_evaluate(&env, evaluator.clone(), proposal_id.clone());
 
         event::evaluate(&env, proposal_id.clone(), evaluator);
 
-        proposal::revoke_voting_power::evaluate(&env, proposal_id);
+        proposal::batch_grant_voting_power::evaluate(&env, proposal_id);
     }
 
-    /// Proposal to create a general purpose proposal.
+    /// Proposal to revoke voting power from members in one batch.
     ///
     /// # Arguments
     ///
     /// - `proposal` - The proposal.
-    pub fn general_purpose_proposal(
+    pub fn batch_revoke_proposal(
         env: Env,
-        proposal: types::proposal::GeneralPurpose,
+        proposal: types::proposal::BatchRevokeVotingPower,
     ) -> BytesN<32> {
         proposal.metadata.proposer.require_auth();
 
-        proposal::general_purpose::write(&env, proposal.clone())
+        proposal::batch_revoke_voting_power::write(&env, proposal.clone())
     }
 
-    /// Returns the proposal to create a general purpose proposal.
+    /// Returns the proposal to revoke voting power from members in one batch.
     ///
     /// # Arguments
     ///
     /// - `proposal_id` - The id of the proposal.
-    pub fn get_gneral_purpose_proposal(
+    pub fn get_batch_revoke_proposal(
         env: Env,
         proposal_id: BytesN<32>,
-    ) -> types::proposal::GeneralPurpose {
-        proposal::general_purpose::read(&env, proposal_id)
+    ) -> types::proposal::BatchRevokeVotingPower {
+        proposal::batch_revoke_voting_power::read(&env, proposal_id)
     }
 
-    /// Evaluates the proposal to create a general purpose proposal.
+    /// Evaluates the proposal to revoke voting power from members in one batch.
+    /// if `quorum` has been reached then the voting power of the members is revoked.
     ///
     /// # Arguments
     ///
     /// - `proposal_id` - The id of the proposal.
-    pub fn evaluate_general_proposal(env: Env, evaluator: Address, proposal_id: BytesN<32>) {
-        if !is_member(&env, evaluator.clone()) {
-            panic!("Evaluator is not a member");
-        }
+    pub fn evaluate_batch_revoke_proposal(env: Env, evaluator: Address, proposal_id: BytesN<32>) {
+        proposal::only_member_evaluate(&env, evaluator.clone(), proposal_id.clone());
 
         event::evaluate(&env, proposal_id.clone(), evaluator);
 
-        proposal::general_purpose::evaluate(&env, proposal_id);
+        proposal::batch_revoke_voting_power::evaluate(&env, proposal_id);
+    }
+}
+
+/// Removes a member leaving the DAO on their own and burns their voting power.
+fn exit(env: &Env, member: Address) {
+    if !is_member(env, member.clone()) {
+        panic!("Member does not exist");
+    }
+
+    if !proposal::keeps_min_members(env, 1) {
+        panic!("DAO must keep atleast min_members members");
     }
+
+    if !proposal::keeps_min_voting_members(env, &vec![env, member.clone()]) {
+        panic!("DAO must keep atleast min_voting_members voting members");
+    }
+
+    remove_member(env, member.clone());
+
+    event::remove_member(env, member);
 }
 
 fn has_voting_power(env: &Env, member: Address) -> bool {
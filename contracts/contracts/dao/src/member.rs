@@ -1,73 +1,283 @@
 /// Module for modifying DAO members.
-use soroban_sdk::{vec, Address, Env, Vec};
+///
+/// Every member is stored under its own key together with its position in a dense index,
+/// so membership checks and updates don't have to load all members.
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
 
+use crate::dao_token;
+use crate::pause;
+use crate::role;
+use crate::ttl;
+use crate::types;
 use crate::types::data_key;
 
-/// Adds members to the DAO.
+/// The minimum number of members a DAO must have.
+pub const MIN_MEMBERS: u32 = 3;
+
+/// Adds the initial members to the DAO.
+/// Panics on duplicates, which would take two slots of the dense index.
 ///
 /// # Arguments
 ///
 /// - `members` - The members to add.
 pub fn add_members(env: &Env, members: Vec<Address>) {
-    for member in members.clone() {
-        if is_member(env, member) {
+    for member in members {
+        if is_member(env, member.clone()) {
             panic!("Member already exists");
         }
+
+        insert(env, member, types::member::Admission::Initial);
     }
+}
 
-    let mut previous_members = read_members(env);
+/// Adds a single member admitted by a proposal to the DAO.
+///
+/// # Arguments
+///
+/// - `member` - The member to add.
+/// - `proposal_id` - The id of the proposal which admitted the member.
+pub fn add_member(env: &Env, member: Address, proposal_id: BytesN<32>) {
+    if is_member(env, member.clone()) {
+        panic!("Member already exists");
+    }
 
-    previous_members.append(&members);
+    insert(env, member, types::member::Admission::Proposal(proposal_id));
+}
 
-    env.storage()
-        .persistent()
-        .set(&data_key::Dao::Members, &previous_members);
+fn insert(env: &Env, member: Address, admission: types::member::Admission) {
+    let count = read_count(env);
+
+    write_at(env, count, member.clone());
+    write_count(env, count + 1);
+
+    write_record(
+        env,
+        types::member::Member {
+            address: member,
+            joined_at: env.ledger().timestamp(),
+            admission,
+            name: String::from_str(env, ""),
+            profile_hash: BytesN::from_array(env, &[0; 32]),
+        },
+    );
 }
 
-/// Adds a single member to the DAO.
+/// Reads the record of a member.
+/// Members migrated from the legacy members vector have no join time and count as initial members.
 ///
 /// # Arguments
 ///
-/// - `member` - The member to add.
-pub fn add_member(env: &Env, member: Address) {
-    add_members(env, vec![env, member]);
+/// - `member` - The member.
+pub fn read_member(env: &Env, member: Address) -> types::member::Member {
+    if !is_member(env, member.clone()) {
+        panic!("Member does not exist");
+    }
+
+    ttl::get(env, &data_key::Dao::Profile(member.clone())).unwrap_or(types::member::Member {
+        address: member,
+        joined_at: 0,
+        admission: types::member::Admission::Initial,
+        name: String::from_str(env, ""),
+        profile_hash: BytesN::from_array(env, &[0; 32]),
+    })
+}
+
+/// Updates the display name and profile document hash of a member.
+///
+/// # Arguments
+///
+/// - `member` - The member.
+/// - `name` - The display name, empty to clear it.
+/// - `profile_hash` - The sha256 hash of the profile document, all zeros to clear it.
+pub fn update_profile(env: &Env, member: Address, name: String, profile_hash: BytesN<32>) {
+    let mut record = read_member(env, member);
+
+    record.name = name;
+    record.profile_hash = profile_hash;
+
+    write_record(env, record);
+}
+
+fn write_record(env: &Env, record: types::member::Member) {
+    ttl::set(
+        env,
+        &data_key::Dao::Profile(record.address.clone()),
+        &record,
+    );
 }
 
-/// Removes members from the DAO.
+/// Removes a member from the DAO and burns their voting power.
 ///
 /// # Arguments
 ///
-/// - `members` - The members to remove.
+/// - `member` - The member to remove.
 pub fn remove_member(env: &Env, member: Address) {
     if !is_member(env, member.clone()) {
         panic!("Member does not exist");
     }
 
-    let mut previous_members = read_members(env);
+    let balance = dao_token::balance(env, member.clone());
+
+    if balance > 0 {
+        dao_token::burn(env, member.clone(), balance);
+        role::remove_voter(env, member.clone());
+        pause::remove_votes(env, member.clone());
+    }
+
+    if let Some(index) = read_index(env, member.clone()) {
+        // move the last member into the freed slot to keep the index dense
+        let last = read_count(env) - 1;
+
+        if index != last {
+            write_at(env, index, read_at(env, last));
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&data_key::Dao::MemberAt(last));
+        env.storage()
+            .persistent()
+            .remove(&data_key::Dao::Member(member.clone()));
+
+        write_count(env, last);
+    } else {
+        let mut legacy_members = read_legacy_members(env);
+
+        let index = legacy_members.first_index_of(&member).unwrap();
 
-    let index = previous_members.first_index_of(&member).unwrap();
+        legacy_members.remove(index);
 
-    previous_members.remove(index);
+        write_legacy_members(env, legacy_members);
+    }
 
     env.storage()
         .persistent()
-        .set(&data_key::Dao::Members, &previous_members);
+        .remove(&data_key::Dao::Profile(member.clone()));
+
+    role::clear_roles(env, member);
 }
 
-/// Reads the members of the DAO.
-pub fn read_members(env: &Env) -> Vec<Address> {
-    env.storage()
-        .persistent()
-        .get(&data_key::Dao::Members)
-        .unwrap_or(Vec::new(env))
+/// Reads a page of the members of the DAO.
+///
+/// # Arguments
+///
+/// - `offset` - The index of the first member to read.
+/// - `limit` - The maximum number of members to read.
+pub fn read_members(env: &Env, offset: u32, limit: u32) -> Vec<Address> {
+    let count = read_count(env);
+    let end = offset.saturating_add(limit).min(total(env));
+
+    let mut members = Vec::new(env);
+    let mut legacy_members = None;
+
+    for index in offset..end {
+        if index < count {
+            members.push_back(read_at(env, index));
+        } else {
+            let legacy = legacy_members.get_or_insert_with(|| read_legacy_members(env));
+
+            members.push_back(legacy.get(index - count).unwrap());
+        }
+    }
+
+    members
 }
 
 /// Checks if a member exists in the DAO.
 pub fn is_member(env: &Env, member: Address) -> bool {
-    read_members(env).contains(&member)
+    env.storage()
+        .persistent()
+        .has(&data_key::Dao::Member(member.clone()))
+        || (has_legacy_members(env) && read_legacy_members(env).contains(&member))
 }
 
 /// Returns the total number of members in the DAO.
 pub fn total(env: &Env) -> u32 {
-    read_members(env).len()
+    let legacy_total = if has_legacy_members(env) {
+        read_legacy_members(env).len()
+    } else {
+        0
+    };
+
+    read_count(env) + legacy_total
+}
+
+/// Moves up to `limit` members from the legacy members vector into per-member storage.
+/// Legacy members with voting power are counted as voters and their tokens are added to the
+/// total supply. Returns the number of members still left to migrate.
+///
+/// # Arguments
+///
+/// - `limit` - The maximum number of members to migrate.
+pub fn migrate(env: &Env, limit: u32) -> u32 {
+    if !has_legacy_members(env) {
+        return 0;
+    }
+
+    let mut legacy_members = read_legacy_members(env);
+    let mut count = read_count(env);
+    let mut supply = 0;
+
+    for _ in 0..limit.min(legacy_members.len()) {
+        let member = legacy_members.pop_front().unwrap();
+
+        write_at(env, count, member.clone());
+        count += 1;
+
+        let balance = dao_token::balance(env, member.clone());
+
+        if balance > 0 {
+            role::migrate_voter(env, member);
+            supply += balance;
+        }
+    }
+
+    write_count(env, count);
+
+    if supply > 0 {
+        dao_token::migrate_supply(env, supply);
+    }
+
+    let remaining = legacy_members.len();
+
+    if remaining == 0 {
+        env.storage().persistent().remove(&data_key::Dao::Members);
+    } else {
+        write_legacy_members(env, legacy_members);
+    }
+
+    remaining
+}
+
+fn read_count(env: &Env) -> u32 {
+    ttl::get(env, &data_key::Dao::MemberCount).unwrap_or(0)
+}
+
+fn write_count(env: &Env, count: u32) {
+    ttl::set(env, &data_key::Dao::MemberCount, &count);
+}
+
+fn read_index(env: &Env, member: Address) -> Option<u32> {
+    ttl::get(env, &data_key::Dao::Member(member))
+}
+
+fn read_at(env: &Env, index: u32) -> Address {
+    ttl::get(env, &data_key::Dao::MemberAt(index)).unwrap()
+}
+
+fn write_at(env: &Env, index: u32, member: Address) {
+    ttl::set(env, &data_key::Dao::MemberAt(index), &member);
+    ttl::set(env, &data_key::Dao::Member(member), &index);
+}
+
+fn has_legacy_members(env: &Env) -> bool {
+    env.storage().persistent().has(&data_key::Dao::Members)
+}
+
+fn read_legacy_members(env: &Env) -> Vec<Address> {
+    ttl::get(env, &data_key::Dao::Members).unwrap_or(Vec::new(env))
+}
+
+fn write_legacy_members(env: &Env, members: Vec<Address>) {
+    ttl::set(env, &data_key::Dao::Members, &members);
 }
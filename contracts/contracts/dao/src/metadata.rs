@@ -1,3 +1,4 @@
+use crate::ttl;
 use crate::types;
 use crate::types::*;
 use soroban_sdk::{BytesN, Env};
@@ -12,17 +13,13 @@ pub mod dao {
     ///
     /// - `metadata` - The metadata to write.
     pub fn write(env: &Env, metadata: types::dao::Metadata) {
-        env.storage()
-            .persistent()
-            .set(&data_key::Dao::Metadata, &metadata);
+        ttl::write_thresholds(env, &metadata);
+        ttl::set(env, &data_key::Dao::Metadata, &metadata);
     }
 
     /// Reads the DAO metadata.
     pub fn read(env: &Env) -> types::dao::Metadata {
-        env.storage()
-            .persistent()
-            .get(&data_key::Dao::Metadata)
-            .unwrap()
+        ttl::get(env, &data_key::Dao::Metadata).unwrap()
     }
 }
 
@@ -37,9 +34,7 @@ pub mod proposal {
     /// - `proposal_id` - The id of the proposal.
     /// - `metadata` - The metadata to write.
     pub fn write(env: &Env, proposal_id: BytesN<32>, metadata: types::proposal::Metadata) {
-        env.storage()
-            .persistent()
-            .set(&data_key::Proposal::Metadata(proposal_id), &metadata);
+        ttl::set(env, &data_key::Proposal::Metadata(proposal_id), &metadata);
     }
 
     /// Reads the proposal metadata.
@@ -48,9 +43,6 @@ pub mod proposal {
     ///
     /// - `proposal_id` - The id of the proposal.
     pub fn read(env: &Env, proposal_id: BytesN<32>) -> types::proposal::Metadata {
-        env.storage()
-            .persistent()
-            .get(&data_key::Proposal::Metadata(proposal_id))
-            .unwrap()
+        ttl::get(env, &data_key::Proposal::Metadata(proposal_id)).unwrap()
     }
 }
@@ -1,6 +1,33 @@
 use super::*;
 
-use soroban_sdk::{Address, Env, String};
+use soroban_sdk::{vec, Address, Env, String, Symbol};
+
+pub fn mock_dao_metadata(env: &Env) -> dao::Metadata {
+    dao::Metadata {
+        min_proposal_duration: 3600_u64,   // 1day
+        max_proposal_duration: 604800_u64, // 7 days
+        min_quorum_percentage: 50_u32,     // 50%
+        voting_mode: types::proposal::VotingMode::Simple,
+        early_resolution: false,
+        optimistic_veto_percentage: 50_u32, // 50%
+        max_pause_duration: 86400_u64,      // 1 day
+        emergency_pause_percentage: 66_u32, // 66%
+        pause_cooldown: 86400_u64,          // 1 day
+        treasury_assets: vec![env],
+        grace_period: 86400_u64,          // 1 day
+        application_duration: 604800_u64, // 7 days
+        consent_period: 86400_u64,        // 1 day
+        min_members: 2_u32,
+        min_voting_members: 2_u32,
+        ttl_threshold: 120960_u32, // 7 days of ledgers
+        ttl_extend_to: 518400_u32, // 30 days of ledgers
+        prune_delay: 604800_u64,   // 7 days
+        proposal_bond: dao::Bond::Disabled,
+        bond_min_turnout_percentage: 20_u32, // 20%
+        max_open_proposals: 0_u32,
+        proposal_cooldown: 0_u64,
+    }
+}
 
 pub fn mock_proposal_metadata(
     env: &Env,
@@ -14,6 +41,10 @@ pub fn mock_proposal_metadata(
         proposer: proposer.clone(),
         start_time: start_time.unwrap_or(5100_u64),
         end_time: end_time.unwrap_or(26000_u64),
+        voting_mode: types::proposal::VotingMode::DaoDefault,
+        ballot: types::proposal::Ballot::Open,
+        temperature_check: types::proposal::TemperatureCheck::Disabled,
+        dependencies: Vec::new(env),
     }
 }
 
@@ -55,5 +86,63 @@ pub fn mock_proposal(
                 actions: String::from_str(&env, "mock actions"),
             })
         }
+        types::proposal::Type::SetGuardians => {
+            dao.set_guardians_proposal(&types::proposal::SetGuardians {
+                metadata: mock_proposal_metadata(env, proposer, start_time, end_time),
+                guardians: vec![env, candidate.clone()],
+            })
+        }
+        types::proposal::Type::GrantRole => dao.grant_role_proposal(&types::proposal::GrantRole {
+            metadata: mock_proposal_metadata(env, proposer, start_time, end_time),
+            candidate: candidate.clone(),
+            role: Symbol::new(env, "council"),
+        }),
+        types::proposal::Type::RevokeRole => {
+            dao.revoke_role_proposal(&types::proposal::RevokeRole {
+                metadata: mock_proposal_metadata(env, proposer, start_time, end_time),
+                candidate: candidate.clone(),
+                role: Symbol::new(env, "council"),
+            })
+        }
+        types::proposal::Type::SetPermission => {
+            dao.set_permission_proposal(&types::proposal::SetPermission {
+                metadata: mock_proposal_metadata(env, proposer, start_time, end_time),
+                permission: types::role::Permission {
+                    proposal_type: types::proposal::Type::GeneralPurpose,
+                    action: types::role::Action::Propose,
+                    roles: vec![env, Symbol::new(env, "council")],
+                },
+            })
+        }
+        types::proposal::Type::Spend => dao.spend_proposal(&types::proposal::Spend {
+            metadata: mock_proposal_metadata(env, proposer, start_time, end_time),
+            asset: dao.get_treasury_assets().get(0).unwrap(),
+            recipient: candidate.clone(),
+            amount: 100,
+        }),
+        types::proposal::Type::BatchAddMember => {
+            dao.batch_add_proposal(&types::proposal::BatchAddMember {
+                metadata: mock_proposal_metadata(env, proposer, start_time, end_time),
+                candidates: vec![env, candidate.clone()],
+            })
+        }
+        types::proposal::Type::BatchRemoveMember => {
+            dao.batch_remove_proposal(&types::proposal::BatchRemoveMember {
+                metadata: mock_proposal_metadata(env, proposer, start_time, end_time),
+                candidates: vec![env, candidate.clone()],
+            })
+        }
+        types::proposal::Type::BatchGrantVotingPower => {
+            dao.batch_grant_proposal(&types::proposal::BatchGrantVotingPower {
+                metadata: mock_proposal_metadata(env, proposer, start_time, end_time),
+                candidates: vec![env, candidate.clone()],
+            })
+        }
+        types::proposal::Type::BatchRevokeVotingPower => {
+            dao.batch_revoke_proposal(&types::proposal::BatchRevokeVotingPower {
+                metadata: mock_proposal_metadata(env, proposer, start_time, end_time),
+                candidates: vec![env, candidate.clone()],
+            })
+        }
     }
 }
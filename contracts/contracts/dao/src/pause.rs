@@ -0,0 +1,151 @@
+/// Module for the emergency pause of the DAO.
+/// A paused DAO rejects proposal creation, voting and evaluation until it is unpaused or the pause expires.
+use soroban_sdk::{Address, Env, Map};
+
+use crate::event;
+use crate::guardian;
+use crate::metadata;
+use crate::proposal;
+use crate::ttl;
+use crate::types::data_key;
+
+/// Pauses the DAO right away if the caller is a guardian. Otherwise the caller's vote
+/// for an emergency pause is recorded and the DAO is paused once the votes exceed
+/// the emergency pause percentage of the voting members.
+/// Votes expire after `max_pause_duration` and are dropped when the voter loses their voting power.
+/// Guardians cannot pause the DAO again during the pause cooldown after a pause ended,
+/// so they cannot keep the DAO frozen on their own.
+///
+/// # Arguments
+///
+/// - `caller` - The guardian or member pausing the DAO.
+pub fn pause(env: &Env, caller: Address) {
+    if is_paused(env) {
+        panic!("DAO is already paused");
+    }
+
+    let mut guardian_pause = guardian::is_guardian(env, caller.clone());
+
+    if guardian_pause && is_cooling_down(env) {
+        // a guardian who is also a voting member can still vote for an emergency pause
+        if !crate::has_voting_power(env, caller.clone()) {
+            panic!("Pause cooldown has not passed yet");
+        }
+
+        guardian_pause = false;
+    }
+
+    if guardian_pause || record_emergency_vote(env, data_key::Dao::PauseVotes, caller.clone()) {
+        let paused_until = env.ledger().timestamp() + metadata::dao::read(env).max_pause_duration;
+
+        ttl::set(env, &data_key::Dao::PausedUntil, &paused_until);
+
+        clear_votes(env);
+
+        event::paused(env, caller, paused_until);
+    }
+}
+
+/// Unpauses the DAO right away if the caller is a guardian. Otherwise the caller's vote
+/// to unpause is recorded and the DAO is unpaused once the votes exceed the emergency
+/// pause percentage of the voting members.
+///
+/// # Arguments
+///
+/// - `caller` - The guardian or member unpausing the DAO.
+pub fn unpause(env: &Env, caller: Address) {
+    if !is_paused(env) {
+        panic!("DAO is not paused");
+    }
+
+    if guardian::is_guardian(env, caller.clone())
+        || record_emergency_vote(env, data_key::Dao::UnpauseVotes, caller.clone())
+    {
+        // the pause ends now, which starts the pause cooldown
+        ttl::set(env, &data_key::Dao::PausedUntil, &env.ledger().timestamp());
+
+        clear_votes(env);
+
+        event::unpaused(env, caller);
+    }
+}
+
+/// Returns the time until which the DAO is or was last paused, zero if it has never been paused.
+pub fn read_paused_until(env: &Env) -> u64 {
+    ttl::get(env, &data_key::Dao::PausedUntil).unwrap_or(0)
+}
+
+/// Checks if the DAO is paused. A pause expires on its own after `max_pause_duration`.
+pub fn is_paused(env: &Env) -> bool {
+    read_paused_until(env) > env.ledger().timestamp()
+}
+
+/// Checks if a pause ended less than `pause_cooldown` ago.
+fn is_cooling_down(env: &Env) -> bool {
+    let paused_until = read_paused_until(env);
+
+    paused_until > 0
+        && paused_until + metadata::dao::read(env).pause_cooldown > env.ledger().timestamp()
+}
+
+/// Panics if the DAO is paused.
+pub fn only_unpaused(env: &Env) {
+    if is_paused(env) {
+        panic!("DAO is paused");
+    }
+}
+
+/// Drops the pause and unpause votes of a member who lost their voting power.
+///
+/// # Arguments
+///
+/// - `member` - The member.
+pub fn remove_votes(env: &Env, member: Address) {
+    for key in [data_key::Dao::PauseVotes, data_key::Dao::UnpauseVotes] {
+        let mut votes: Map<Address, u64> = ttl::get(env, &key).unwrap_or(Map::new(env));
+
+        if votes.remove(member.clone()).is_some() {
+            ttl::set(env, &key, &votes);
+        }
+    }
+}
+
+/// Records the vote of a voting member and returns true if the votes exceed the emergency pause percentage.
+fn record_emergency_vote(env: &Env, key: data_key::Dao, voter: Address) -> bool {
+    if !crate::has_voting_power(env, voter.clone()) {
+        panic!("Only guardians or voting members can pause or unpause the DAO");
+    }
+
+    let dao_metadata = metadata::dao::read(env);
+    let now = env.ledger().timestamp();
+
+    let stored: Map<Address, u64> = ttl::get(env, &key).unwrap_or(Map::new(env));
+
+    // votes older than a pause window are expired
+    let mut votes = Map::new(env);
+
+    for (member, voted_at) in stored.iter() {
+        if voted_at + dao_metadata.max_pause_duration > now {
+            votes.set(member, voted_at);
+        }
+    }
+
+    if votes.contains_key(voter.clone()) {
+        panic!("Member has already voted");
+    }
+
+    votes.set(voter, now);
+
+    ttl::set(env, &key, &votes);
+
+    votes.len() > (proposal::voting_members(env) * dao_metadata.emergency_pause_percentage) / 100
+}
+
+fn clear_votes(env: &Env) {
+    env.storage()
+        .persistent()
+        .remove(&data_key::Dao::PauseVotes);
+    env.storage()
+        .persistent()
+        .remove(&data_key::Dao::UnpauseVotes);
+}
@@ -3,37 +3,220 @@
 /// This module contains functions for managing proposals.
 use crate::dao_token;
 use crate::event;
+use crate::guardian;
 use crate::member;
 use crate::metadata;
+use crate::pause;
+use crate::role;
+use crate::ttl;
 use crate::types;
 use crate::types::*;
-use soroban_sdk::{Address, Bytes, BytesN, Env, Vec};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{vec, Address, Bytes, BytesN, Env, IntoVal, Map, Symbol, Val, Vec};
 
 pub mod add_member;
+pub mod batch_add_member;
+pub mod batch_grant_voting_power;
+pub mod batch_remove_member;
+pub mod batch_revoke_voting_power;
 pub mod general_purpose;
+pub mod grant_role;
 pub mod grant_voting_power;
 pub mod remove_member;
+pub mod revoke_role;
 pub mod revoke_voting_power;
+pub mod set_guardians;
+pub mod set_permission;
+pub mod spend;
 
 pub mod vote;
 pub use vote::*;
 
+pub mod objection;
+pub use objection::*;
+
+pub mod signal;
+pub use signal::*;
+
+pub mod veto;
+pub use veto::*;
+
 pub mod status;
 pub use status::*;
 
+pub mod consent;
+pub use consent::*;
+
+pub mod prune;
+pub use prune::*;
+
+pub mod bond;
+pub use bond::read_bond;
+
+pub mod limit;
+pub use limit::read_proposer_status;
+
+pub mod amend;
+pub use amend::*;
+
+pub mod dependency;
+pub use dependency::{read_dependencies, read_dependents};
+
 /// Returns true if the quorum is reached.
 fn is_quorum_reached(env: &Env, proposal_id: BytesN<32>) -> bool {
-    let total_votes = read_votes(env, proposal_id.clone()).len();
+    let total_votes = read_tally(env, proposal_id.clone()) as u64;
+
+    total_votes > quorum_threshold(env, proposal_id)
+}
+
+/// Returns the effective votes a proposal has to exceed to reach the quorum.
+fn quorum_threshold(env: &Env, proposal_id: BytesN<32>) -> u64 {
+    let quorum_percentage = metadata::dao::read(env).min_quorum_percentage as u64;
+
+    let possible_votes = max_tally(env, proposal_id) as u64;
+
+    (possible_votes * quorum_percentage) / 100
+}
+
+/// Returns true if the objections to an optimistic proposal reach the veto threshold.
+fn is_veto_reached(env: &Env, proposal_id: BytesN<32>) -> bool {
+    let total_objections = total_objections(env, proposal_id.clone()) as u64;
+
+    total_objections > veto_threshold(env, proposal_id)
+}
+
+/// Returns the objections an optimistic proposal has to exceed to be vetoed.
+/// Only members permitted to vote on the proposal can object to it.
+fn veto_threshold(env: &Env, proposal_id: BytesN<32>) -> u64 {
+    let veto_percentage = metadata::dao::read(env).optimistic_veto_percentage as u64;
+    let voters = role::count_voters(env, read_type(env, proposal_id)) as u64;
+
+    (voters * veto_percentage) / 100
+}
+
+/// Returns true if an ended proposal is accepted according to its votes or objections.
+fn is_accepted(
+    env: &Env,
+    proposal_id: BytesN<32>,
+    proposal_metadata: &types::proposal::Metadata,
+) -> bool {
+    match proposal_metadata.ballot {
+        types::proposal::Ballot::Optimistic => !is_veto_reached(env, proposal_id),
+        _ => is_quorum_reached(env, proposal_id),
+    }
+}
+
+/// Returns the outcome of an active proposal if the votes or objections that can still be cast cannot change it.
+fn decided_outcome(
+    env: &Env,
+    proposal_id: BytesN<32>,
+    proposal_metadata: &types::proposal::Metadata,
+) -> Option<bool> {
+    let optimistic = proposal_metadata.ballot == types::proposal::Ballot::Optimistic;
+
+    let (total, cast, threshold, voting_mode) = if optimistic {
+        let objections = total_objections(env, proposal_id.clone());
+
+        (
+            objections as u64,
+            objections,
+            veto_threshold(env, proposal_id.clone()),
+            types::proposal::VotingMode::Simple,
+        )
+    } else {
+        (
+            read_tally(env, proposal_id.clone()) as u64,
+            total_votes(env, proposal_id.clone()),
+            quorum_threshold(env, proposal_id.clone()),
+            read_voting_mode(env, proposal_id.clone()),
+        )
+    };
+
+    let threshold_exceeded = if total > threshold {
+        Some(true)
+    } else {
+        // every permitted voter who has not voted or objected yet may still add their votes
+        let voters = role::count_voters(env, read_type(env, proposal_id));
+        let remaining = voters.saturating_sub(cast) as u64 * max_votes(&voting_mode) as u64;
+
+        if total + remaining <= threshold {
+            Some(false)
+        } else {
+            None
+        }
+    };
 
-    let quorum_percentage = metadata::dao::read(env).min_quorum_percentage;
+    // an optimistic proposal is accepted unless its objections exceed the threshold
+    threshold_exceeded.map(|exceeded| exceeded != optimistic)
+}
+
+/// Accepts or rejects an ended proposal according to its votes or objections. Returns true if the proposal is accepted.
+/// If the DAO allows early resolution, an active proposal whose outcome is already decided is resolved as well.
+/// Panics if the proposal is already evaluated or cannot be evaluated yet.
+fn finalize(
+    env: &Env,
+    proposal_id: BytesN<32>,
+    proposal_metadata: &types::proposal::Metadata,
+) -> bool {
+    finalize_applicable(env, proposal_id, proposal_metadata, true)
+}
+
+/// Finalizes a proposal like [`finalize`], but rejects it if it can no longer be applied,
+/// so its status is written only once.
+fn finalize_applicable(
+    env: &Env,
+    proposal_id: BytesN<32>,
+    proposal_metadata: &types::proposal::Metadata,
+    applicable: bool,
+) -> bool {
+    pause::only_unpaused(env);
+
+    if is_finalized(env, proposal_id.clone()) {
+        panic!("Proposal already evaluated");
+    }
+
+    let accepted = match timed_status(env, proposal_id.clone(), proposal_metadata) {
+        types::proposal::Status::TemperatureCheckFailed => false,
+        types::proposal::Status::Ended => {
+            if let types::proposal::Ballot::Secret(reveal_duration) = proposal_metadata.ballot {
+                if proposal_metadata.end_time + reveal_duration >= env.ledger().timestamp() {
+                    panic!("Reveal phase has not ended yet");
+                }
+            }
+
+            is_accepted(env, proposal_id.clone(), proposal_metadata)
+        }
+        types::proposal::Status::Active
+            if metadata::dao::read(env).early_resolution
+                && !matches!(proposal_metadata.ballot, types::proposal::Ballot::Secret(_)) =>
+        {
+            if let Some(accepted) = decided_outcome(env, proposal_id.clone(), proposal_metadata) {
+                event::early_resolution(env, proposal_id.clone(), accepted);
 
-    let total_members = member::total(env);
+                accepted
+            } else {
+                panic!("Proposal outcome is not decided yet");
+            }
+        }
+        _ => panic!("Proposal is not ended yet"),
+    };
 
-    total_votes > (total_members * quorum_percentage) / 100
+    // a proposal whose prerequisites were not accepted is rejected
+    let accepted = accepted && dependency::are_applied(env, proposal_metadata) && applicable;
+
+    if accepted {
+        write_status(env, proposal_id, types::proposal::Status::Accepted);
+    } else {
+        write_status(env, proposal_id, types::proposal::Status::Rejected);
+    }
+
+    accepted
 }
 
 /// Validates the proposal according to DAO metadata. Panics if the proposal is invalid.
 fn validate_proposal(env: &Env, proposal_metadata: &types::proposal::Metadata) {
+    pause::only_unpaused(env);
+
     if proposal_metadata.end_time < proposal_metadata.start_time {
         panic!("End time must be after start time");
     }
@@ -42,6 +225,24 @@ fn validate_proposal(env: &Env, proposal_metadata: &types::proposal::Metadata) {
         panic!("Start time must be in the future");
     }
 
+    if let types::proposal::TemperatureCheck::Signal(duration, percentage) =
+        proposal_metadata.temperature_check
+    {
+        if duration == 0 {
+            panic!("Temperature check duration must be greater than zero");
+        }
+
+        if percentage == 0 || percentage > 100 {
+            panic!("Temperature check percentage must be between 1 and 100");
+        }
+
+        if proposal_metadata.start_time < env.ledger().timestamp() + duration {
+            panic!("Temperature check must start in the future");
+        }
+    }
+
+    limit::check_cooldown(env, proposal_metadata.proposer.clone());
+
     let dao_metadata = metadata::dao::read(env);
     let proposal_duration = proposal_metadata.end_time - proposal_metadata.start_time;
 
@@ -52,6 +253,34 @@ fn validate_proposal(env: &Env, proposal_metadata: &types::proposal::Metadata) {
     if proposal_duration < dao_metadata.min_proposal_duration {
         panic!("Proposal duration is less than min_proposal_duration");
     }
+
+    let voting_mode = match proposal_metadata.voting_mode.clone() {
+        types::proposal::VotingMode::DaoDefault => dao_metadata.voting_mode,
+        voting_mode => voting_mode,
+    };
+
+    if let types::proposal::VotingMode::Quadratic(
+        types::proposal::VoiceCredits::Flat(0) | types::proposal::VoiceCredits::PerToken(0),
+    ) = voting_mode
+    {
+        panic!("Voice credits must be greater than zero");
+    }
+
+    if let types::proposal::Ballot::Secret(reveal_duration) = proposal_metadata.ballot {
+        if reveal_duration == 0 {
+            panic!("Reveal duration must be greater than zero");
+        }
+
+        if let types::proposal::VotingMode::Quadratic(_) = voting_mode {
+            panic!("Secret ballots do not support quadratic voting");
+        }
+    }
+
+    if proposal_metadata.ballot == types::proposal::Ballot::Optimistic {
+        if let types::proposal::VotingMode::Quadratic(_) = voting_mode {
+            panic!("Optimistic proposals do not support quadratic voting");
+        }
+    }
 }
 
 /// Checks if the proposal is still valid for actions such as voting. Panics if the proposal is invalid.
@@ -60,34 +289,245 @@ fn check_proposal(
     proposal_id: BytesN<32>,
     proposal_metadata: &types::proposal::Metadata,
 ) {
-    if proposal_metadata.end_time < env.ledger().timestamp() {
-        write_status(env, proposal_id.clone(), types::proposal::Status::Ended);
-        panic!("Proposal has ended");
-    } else if proposal_metadata.start_time > env.ledger().timestamp() {
-        write_status(
-            env,
-            proposal_id.clone(),
-            types::proposal::Status::NotStarted,
-        );
-        panic!("Proposal has not started");
+    if is_finalized(env, proposal_id.clone()) {
+        panic!("Proposal already evaluated");
+    }
+
+    match timed_status(env, proposal_id, proposal_metadata) {
+        types::proposal::Status::Ended => panic!("Proposal has ended"),
+        types::proposal::Status::NotStarted => panic!("Proposal has not started"),
+        types::proposal::Status::TemperatureCheck => {
+            panic!("Proposal is in its temperature check")
+        }
+        types::proposal::Status::TemperatureCheckFailed => {
+            panic!("Proposal failed its temperature check")
+        }
+        _ => {}
+    }
+}
+
+/// Returns the number of members with voting power.
+/// Voting power is granted as exactly one dao token and burned when a member is removed,
+/// so this is the total supply.
+pub fn voting_members(env: &Env) -> u32 {
+    dao_token::total_supply(env) as u32
+}
+
+/// Returns true if the DAO still has at least `min_members` members after removing some.
+///
+/// # Arguments
+///
+/// - `removed` - The number of members removed.
+pub fn keeps_min_members(env: &Env, removed: u32) -> bool {
+    member::total(env) >= metadata::dao::read(env).min_members + removed
+}
+
+/// Returns true if the DAO still has at least `min_voting_members` members with voting power
+/// after the candidates lose theirs.
+///
+/// # Arguments
+///
+/// - `candidates` - The members losing their voting power.
+pub fn keeps_min_voting_members(env: &Env, candidates: &Vec<Address>) -> bool {
+    let mut losing = 0;
+
+    for candidate in candidates.iter() {
+        if crate::has_voting_power(env, candidate) {
+            losing += 1;
+        }
+    }
+
+    losing == 0 || voting_members(env) >= metadata::dao::read(env).min_voting_members + losing
+}
+
+/// Panics if the candidates of a batch proposal are empty or contain duplicates.
+fn check_candidates(candidates: &Vec<Address>) {
+    if candidates.is_empty() {
+        panic!("Candidates must not be empty");
+    }
+
+    for (index, candidate) in candidates.iter().enumerate() {
+        if candidates.first_index_of(&candidate) != Some(index as u32) {
+            panic!("Duplicate candidate");
+        }
+    }
+}
+
+/// Validates a new proposal and stores its metadata and type. Returns the id of the proposal.
+/// Panics if the same proposal has been submitted before.
+fn create<T>(
+    env: &Env,
+    proposal_type: types::proposal::Type,
+    proposal_metadata: &types::proposal::Metadata,
+    proposal: &T,
+) -> BytesN<32>
+where
+    T: IntoVal<Env, Val>,
+{
+    only_member_proposal(env, proposal_metadata.proposer.clone(), proposal_type);
+    validate_proposal(env, proposal_metadata);
+
+    let body_hash = hash_body(env, proposal.into_val(env));
+    let body_key = data_key::Proposal::BodyHash(body_hash.clone());
+
+    if env.storage().persistent().has(&body_key) {
+        panic!("Duplicate proposal");
+    }
+
+    let proposer = proposal_metadata.proposer.clone();
+    let nonce = read_nonce(env, proposer.clone());
+
+    let proposal_id = compute_id(env, body_hash, proposer.clone(), nonce);
+
+    dependency::check(env, proposal_metadata);
+
+    ttl::set(env, &data_key::Dao::Nonce(proposer.clone()), &(nonce + 1));
+    ttl::set(env, &body_key, &proposal_id);
+
+    metadata::proposal::write(env, proposal_id.clone(), proposal_metadata.clone());
+
+    ttl::set(
+        env,
+        &data_key::Proposal::Type(proposal_id.clone()),
+        &proposal_type,
+    );
+
+    dependency::record(env, proposal_id.clone(), proposal_metadata);
+    bond::deposit(env, proposal_id.clone(), proposer.clone());
+    limit::record(env, proposer, proposal_id.clone());
+
+    event::proposal_created(env, proposal_id.clone(), proposal_type, proposal_metadata);
+
+    proposal_id
+}
+
+/// Returns the type of a proposal.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn read_type(env: &Env, proposal_id: BytesN<32>) -> types::proposal::Type {
+    if let Some(proposal_type) = ttl::get(env, &data_key::Proposal::Type(proposal_id)) {
+        proposal_type
+    } else {
+        panic!("Proposal not found");
     }
 }
 
-fn only_member_proposal(env: &Env, caller: Address) {
-    if !member::is_member(&env, caller) {
+/// Returns the key of the type specific body of a proposal.
+///
+/// # Arguments
+///
+/// - `proposal_type` - The type of the proposal.
+/// - `id` - The id of the proposal.
+pub fn body_key(proposal_type: types::proposal::Type, id: BytesN<32>) -> data_key::Proposal {
+    match proposal_type {
+        types::proposal::Type::AddMember => data_key::Proposal::AddMember(id),
+        types::proposal::Type::RemoveMember => data_key::Proposal::RemoveMember(id),
+        types::proposal::Type::GrantVotingPower => data_key::Proposal::GrantVotingPower(id),
+        types::proposal::Type::RevokeVotingPower => data_key::Proposal::RevokeVotingPower(id),
+        types::proposal::Type::GeneralPurpose => data_key::Proposal::GeneralPurpose(id),
+        types::proposal::Type::SetGuardians => data_key::Proposal::SetGuardians(id),
+        types::proposal::Type::GrantRole => data_key::Proposal::GrantRole(id),
+        types::proposal::Type::RevokeRole => data_key::Proposal::RevokeRole(id),
+        types::proposal::Type::SetPermission => data_key::Proposal::SetPermission(id),
+        types::proposal::Type::Spend => data_key::Proposal::Spend(id),
+        types::proposal::Type::BatchAddMember => data_key::Proposal::BatchAddMember(id),
+        types::proposal::Type::BatchRemoveMember => data_key::Proposal::BatchRemoveMember(id),
+        types::proposal::Type::BatchGrantVotingPower => {
+            data_key::Proposal::BatchGrantVotingPower(id)
+        }
+        types::proposal::Type::BatchRevokeVotingPower => {
+            data_key::Proposal::BatchRevokeVotingPower(id)
+        }
+    }
+}
+
+fn only_member_proposal(env: &Env, caller: Address, proposal_type: types::proposal::Type) {
+    if !member::is_member(env, caller.clone()) {
         panic!("Only members can create proposals");
     }
+
+    if !role::is_permitted(
+        env,
+        caller.clone(),
+        proposal_type,
+        types::role::Action::Propose,
+    ) {
+        panic!("Proposer does not have a role allowed to create this proposal");
+    }
+
+    limit::check_open_limit(env, caller);
 }
 
-fn only_member_vote(env: &Env, caller: Address) {
-    if !member::is_member(&env, caller) {
+fn only_member_vote(env: &Env, caller: Address, proposal_id: BytesN<32>) {
+    if !member::is_member(env, caller.clone()) {
         panic!("Only members can vote");
     }
+
+    let proposal_type = read_type(env, proposal_id);
+
+    if !role::is_permitted(env, caller, proposal_type, types::role::Action::Vote) {
+        panic!("Voter does not have a role allowed to vote on this proposal");
+    }
 }
 
-fn generate_unique_id(env: &Env) -> BytesN<32> {
-    let mut value = [0u8; 64];
-    env.prng().fill(&mut value);
+/// Panics if the evaluator is not a member with a role allowed to evaluate the proposal.
+///
+/// # Arguments
+///
+/// - `evaluator` - The member evaluating the proposal.
+/// - `proposal_id` - The id of the proposal.
+pub fn only_member_evaluate(env: &Env, evaluator: Address, proposal_id: BytesN<32>) {
+    if !member::is_member(env, evaluator.clone()) {
+        panic!("Evaluator is not a member");
+    }
+
+    let proposal_type = read_type(env, proposal_id);
+
+    if !role::is_permitted(env, evaluator, proposal_type, types::role::Action::Evaluate) {
+        panic!("Evaluator does not have a role allowed to evaluate this proposal");
+    }
+}
+
+/// Returns the id of a proposal, the sha256 hash of the XDR encoded tuple
+/// `(body_hash, proposer, nonce)`, so it can be computed before the proposal is submitted.
+///
+/// # Arguments
+///
+/// - `body_hash` - The sha256 hash of the XDR encoded proposal body.
+/// - `proposer` - The proposer of the proposal.
+/// - `nonce` - The nonce of the proposer when submitting the proposal.
+pub fn compute_id(env: &Env, body_hash: BytesN<32>, proposer: Address, nonce: u64) -> BytesN<32> {
+    env.crypto()
+        .sha256(&(body_hash, proposer, nonce).to_xdr(env))
+}
+
+/// Returns the nonce the next proposal of a proposer is created with.
+///
+/// # Arguments
+///
+/// - `proposer` - The proposer.
+pub fn read_nonce(env: &Env, proposer: Address) -> u64 {
+    ttl::get(env, &data_key::Dao::Nonce(proposer)).unwrap_or(0)
+}
+
+/// Returns the sha256 hash of the XDR encoded body of a proposal.
+///
+/// # Arguments
+///
+/// - `proposal_type` - The type of the proposal.
+/// - `proposal_id` - The id of the proposal.
+pub fn read_body_hash(
+    env: &Env,
+    proposal_type: types::proposal::Type,
+    proposal_id: BytesN<32>,
+) -> BytesN<32> {
+    let body: Val = ttl::get(env, &body_key(proposal_type, proposal_id)).unwrap();
+
+    hash_body(env, body)
+}
 
-    env.crypto().sha256(&Bytes::from_slice(env, &value))
+fn hash_body(env: &Env, body: Val) -> BytesN<32> {
+    env.crypto().sha256(&body.to_xdr(env))
 }
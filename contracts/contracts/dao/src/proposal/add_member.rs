@@ -6,25 +6,39 @@ use super::*;
 ///
 /// - `proposal` - The proposal.
 pub fn write(env: &Env, proposal: types::proposal::AddMember) -> BytesN<32> {
-    if member::is_member(env, proposal.candidate.clone()) {
-        panic!("Already a member");
-    }
-
-    only_member_proposal(env, proposal.metadata.proposer.clone());
-    validate_proposal(env, &proposal.metadata);
+    check(env, &proposal);
 
-    let proposal_id = generate_unique_id(&env);
+    let proposal_id = create(
+        env,
+        types::proposal::Type::AddMember,
+        &proposal.metadata,
+        &proposal,
+    );
 
-    env.storage().persistent().set(
+    ttl::set(
+        env,
         &data_key::Proposal::AddMember(proposal_id.clone()),
         &proposal,
     );
 
-    metadata::proposal::write(&env, proposal_id.clone(), proposal.metadata.clone());
+    proposal_id
+}
 
-    update_status(env, proposal_id.clone(), &proposal.metadata);
+/// Panics if the candidate of the proposal is not valid.
+/// A proposal with dependencies is checked when it is evaluated instead, see [`is_valid`].
+pub(super) fn check(env: &Env, proposal: &types::proposal::AddMember) {
+    if !proposal.metadata.dependencies.is_empty() {
+        return;
+    }
 
-    proposal_id
+    if member::is_member(env, proposal.candidate.clone()) {
+        panic!("Already a member");
+    }
+}
+
+/// Returns true if the candidate of the proposal is still valid when it is evaluated.
+pub(super) fn is_valid(env: &Env, proposal: &types::proposal::AddMember) -> bool {
+    !member::is_member(env, proposal.candidate.clone())
 }
 
 /// Read a add member proposal
@@ -33,11 +47,7 @@ pub fn write(env: &Env, proposal: types::proposal::AddMember) -> BytesN<32> {
 ///
 /// - `proposal_id` - The id of the proposal.
 pub fn read(env: &Env, proposal_id: BytesN<32>) -> types::proposal::AddMember {
-    if let Some(proposal) = env
-        .storage()
-        .persistent()
-        .get(&data_key::Proposal::AddMember(proposal_id))
-    {
+    if let Some(proposal) = ttl::get(env, &data_key::Proposal::AddMember(proposal_id)) {
         proposal
     } else {
         panic!("Proposal not found");
@@ -45,34 +55,31 @@ pub fn read(env: &Env, proposal_id: BytesN<32>) -> types::proposal::AddMember {
 }
 
 /// Evaluate a add member proposal
-/// If the quorum is reached, the proposal will be accepted and the member will be added
+/// If the quorum is reached, the proposal will be accepted and the candidate has to consent
+/// within the consent period before they are added.
 ///
 /// # Arguments
 ///
 /// - `proposal_id` - The id of the proposal.
 pub fn evaluate(env: &Env, proposal_id: BytesN<32>) {
-    let status = read_status(env, proposal_id.clone());
-
-    if status == types::proposal::Status::Accepted || status == types::proposal::Status::Rejected {
-        panic!("Proposal already evaluated");
-    }
-
     let proposal = read(env, proposal_id.clone());
-    update_status(env, proposal_id.clone(), &proposal.metadata);
 
-    if read_status(env, proposal_id.clone()) != types::proposal::Status::Ended {
-        panic!("Proposal is not ended yet");
-    }
+    let applicable = is_valid(env, &proposal);
 
-    if is_quorum_reached(env, proposal_id.clone()) {
-        write_status(env, proposal_id.clone(), types::proposal::Status::Accepted);
+    if finalize_applicable(env, proposal_id.clone(), &proposal.metadata, applicable) {
+        await_consent(env, proposal_id);
+    }
+}
 
-        let candidate = proposal.candidate.clone();
+/// Add the candidate of an accepted add member proposal after they consented
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn apply(env: &Env, proposal_id: BytesN<32>) {
+    let candidate = read(env, proposal_id.clone()).candidate;
 
-        member::add_member(env, candidate.clone());
+    member::add_member(env, candidate.clone(), proposal_id);
 
-        event::add_member(env, candidate);
-    } else {
-        write_status(env, proposal_id.clone(), types::proposal::Status::Rejected);
-    }
+    event::add_member(env, candidate);
 }
@@ -0,0 +1,155 @@
+use super::*;
+
+/// Amends a proposal before its voting opens and records the revision.
+/// The id of the proposal stays the one derived from its original body.
+///
+/// # Arguments
+///
+/// - `proposer` - The proposer of the proposal.
+/// - `proposal_id` - The id of the proposal.
+/// - `amendment` - The change to the proposal.
+pub fn amend(
+    env: &Env,
+    proposer: Address,
+    proposal_id: BytesN<32>,
+    amendment: types::proposal::Amendment,
+) {
+    pause::only_unpaused(env);
+
+    let mut proposal_metadata = metadata::proposal::read(env, proposal_id.clone());
+
+    if proposal_metadata.proposer != proposer {
+        panic!("Only the proposer can amend the proposal");
+    }
+
+    if read_status(env, proposal_id.clone()) != types::proposal::Status::NotStarted {
+        panic!("Proposal can only be amended before it starts");
+    }
+
+    let proposal_type = read_type(env, proposal_id.clone());
+    let previous_hash = read_body_hash(env, proposal_type, proposal_id.clone());
+
+    match amendment {
+        types::proposal::Amendment::Text(name, description) => {
+            proposal_metadata.name = name;
+            proposal_metadata.description = description;
+
+            amend_metadata(
+                env,
+                proposal_type,
+                proposal_id.clone(),
+                proposal_metadata.clone(),
+            );
+
+            metadata::proposal::write(env, proposal_id.clone(), proposal_metadata);
+        }
+        types::proposal::Amendment::Candidate(candidate) => {
+            amend_candidate(env, proposal_type, proposal_id.clone(), candidate)
+        }
+    }
+
+    let body_hash = read_body_hash(env, proposal_type, proposal_id.clone());
+    let body_hash_key = data_key::Proposal::BodyHash(body_hash.clone());
+
+    if env.storage().persistent().has(&body_hash_key) {
+        panic!("Duplicate proposal");
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&data_key::Proposal::BodyHash(previous_hash.clone()));
+    ttl::set(env, &body_hash_key, &proposal_id);
+
+    let mut revisions = read_revisions(env, proposal_id.clone());
+
+    revisions.push_back(types::proposal::Revision {
+        previous_hash: previous_hash.clone(),
+        body_hash: body_hash.clone(),
+        amended_at: env.ledger().timestamp(),
+    });
+
+    ttl::set(
+        env,
+        &data_key::Proposal::Revisions(proposal_id.clone()),
+        &revisions,
+    );
+
+    event::amended(env, proposal_id, previous_hash, body_hash);
+}
+
+/// Returns the revisions of a proposal, oldest first.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn read_revisions(env: &Env, proposal_id: BytesN<32>) -> Vec<types::proposal::Revision> {
+    ttl::get(env, &data_key::Proposal::Revisions(proposal_id)).unwrap_or(Vec::new(env))
+}
+
+/// Replaces the metadata in the body of a proposal of any type.
+/// A body is stored as a map of its fields, so it is amended without knowing its type.
+fn amend_metadata(
+    env: &Env,
+    proposal_type: types::proposal::Type,
+    proposal_id: BytesN<32>,
+    proposal_metadata: types::proposal::Metadata,
+) {
+    let key = body_key(proposal_type, proposal_id);
+
+    let mut body: Map<Symbol, Val> = ttl::get(env, &key).unwrap();
+    body.set(
+        Symbol::new(env, "metadata"),
+        proposal_metadata.into_val(env),
+    );
+
+    ttl::set(env, &key, &body);
+}
+
+fn amend_candidate(
+    env: &Env,
+    proposal_type: types::proposal::Type,
+    proposal_id: BytesN<32>,
+    candidate: Address,
+) {
+    let key = body_key(proposal_type, proposal_id.clone());
+
+    match proposal_type {
+        types::proposal::Type::AddMember => {
+            let mut proposal = add_member::read(env, proposal_id);
+            proposal.candidate = candidate;
+            add_member::check(env, &proposal);
+            ttl::set(env, &key, &proposal);
+        }
+        types::proposal::Type::RemoveMember => {
+            let mut proposal = remove_member::read(env, proposal_id);
+            proposal.candidate = candidate;
+            remove_member::check(env, &proposal);
+            ttl::set(env, &key, &proposal);
+        }
+        types::proposal::Type::GrantVotingPower => {
+            let mut proposal = grant_voting_power::read(env, proposal_id);
+            proposal.candidate = candidate;
+            grant_voting_power::check(env, &proposal);
+            ttl::set(env, &key, &proposal);
+        }
+        types::proposal::Type::RevokeVotingPower => {
+            let mut proposal = revoke_voting_power::read(env, proposal_id);
+            proposal.candidate = candidate;
+            revoke_voting_power::check(env, &proposal);
+            ttl::set(env, &key, &proposal);
+        }
+        types::proposal::Type::GrantRole => {
+            let mut proposal = grant_role::read(env, proposal_id);
+            proposal.candidate = candidate;
+            grant_role::check(env, &proposal);
+            ttl::set(env, &key, &proposal);
+        }
+        types::proposal::Type::RevokeRole => {
+            let mut proposal = revoke_role::read(env, proposal_id);
+            proposal.candidate = candidate;
+            revoke_role::check(env, &proposal);
+            ttl::set(env, &key, &proposal);
+        }
+        _ => panic!("Proposal has no candidate"),
+    }
+}
@@ -0,0 +1,93 @@
+use super::*;
+
+/// Create a new proposal to add members in one batch
+///
+/// # Arguments
+///
+/// - `proposal` - The proposal.
+pub fn write(env: &Env, proposal: types::proposal::BatchAddMember) -> BytesN<32> {
+    check(env, &proposal);
+
+    let proposal_id = create(
+        env,
+        types::proposal::Type::BatchAddMember,
+        &proposal.metadata,
+        &proposal,
+    );
+
+    ttl::set(
+        env,
+        &data_key::Proposal::BatchAddMember(proposal_id.clone()),
+        &proposal,
+    );
+
+    proposal_id
+}
+
+/// Panics if the candidates of the proposal are not valid.
+/// The candidates of a proposal with dependencies are checked when it is evaluated instead,
+/// see [`is_valid`].
+fn check(env: &Env, proposal: &types::proposal::BatchAddMember) {
+    check_candidates(&proposal.candidates);
+
+    if !proposal.metadata.dependencies.is_empty() {
+        return;
+    }
+
+    for candidate in proposal.candidates.iter() {
+        if member::is_member(env, candidate) {
+            panic!("Already a member");
+        }
+    }
+}
+
+/// Returns true if none of the candidates of the proposal has become a member when it is evaluated.
+pub(super) fn is_valid(env: &Env, proposal: &types::proposal::BatchAddMember) -> bool {
+    !proposal
+        .candidates
+        .iter()
+        .any(|candidate| member::is_member(env, candidate))
+}
+
+/// Read a batch add members proposal
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn read(env: &Env, proposal_id: BytesN<32>) -> types::proposal::BatchAddMember {
+    if let Some(proposal) = ttl::get(env, &data_key::Proposal::BatchAddMember(proposal_id)) {
+        proposal
+    } else {
+        panic!("Proposal not found");
+    }
+}
+
+/// Evaluate a batch add members proposal
+/// If the quorum is reached, the proposal will be accepted and the candidates are added
+/// together once all of them consented within the consent period.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn evaluate(env: &Env, proposal_id: BytesN<32>) {
+    let proposal = read(env, proposal_id.clone());
+
+    let applicable = is_valid(env, &proposal);
+
+    if finalize_applicable(env, proposal_id.clone(), &proposal.metadata, applicable) {
+        await_consent(env, proposal_id);
+    }
+}
+
+/// Add all candidates of an accepted batch add members proposal after they consented
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn apply(env: &Env, proposal_id: BytesN<32>) {
+    for candidate in read(env, proposal_id.clone()).candidates {
+        member::add_member(env, candidate.clone(), proposal_id.clone());
+
+        event::add_member(env, candidate);
+    }
+}
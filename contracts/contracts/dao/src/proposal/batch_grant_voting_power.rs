@@ -0,0 +1,96 @@
+use super::*;
+
+/// Create a new proposal to grant voting power to members in one batch
+///
+/// # Arguments
+///
+/// - `proposal` - The proposal.
+pub fn write(env: &Env, proposal: types::proposal::BatchGrantVotingPower) -> BytesN<32> {
+    check(env, &proposal);
+
+    let proposal_id = create(
+        env,
+        types::proposal::Type::BatchGrantVotingPower,
+        &proposal.metadata,
+        &proposal,
+    );
+
+    ttl::set(
+        env,
+        &data_key::Proposal::BatchGrantVotingPower(proposal_id.clone()),
+        &proposal,
+    );
+
+    proposal_id
+}
+
+/// Panics if the candidates of the proposal are not valid.
+/// The candidates of a proposal with dependencies are checked when it is evaluated instead,
+/// see [`is_valid`].
+fn check(env: &Env, proposal: &types::proposal::BatchGrantVotingPower) {
+    check_candidates(&proposal.candidates);
+
+    if !proposal.metadata.dependencies.is_empty() {
+        return;
+    }
+
+    for candidate in proposal.candidates.iter() {
+        if !member::is_member(env, candidate.clone()) {
+            panic!("Member does not exist");
+        }
+
+        if crate::has_voting_power(env, candidate) {
+            panic!("Already has voting power");
+        }
+    }
+}
+
+/// Returns true if all candidates of the proposal are still members without voting power.
+pub(super) fn is_valid(env: &Env, proposal: &types::proposal::BatchGrantVotingPower) -> bool {
+    proposal.candidates.iter().all(|candidate| {
+        member::is_member(env, candidate.clone()) && !crate::has_voting_power(env, candidate)
+    })
+}
+
+/// Read a batch grant voting power proposal
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn read(env: &Env, proposal_id: BytesN<32>) -> types::proposal::BatchGrantVotingPower {
+    if let Some(proposal) = ttl::get(env, &data_key::Proposal::BatchGrantVotingPower(proposal_id)) {
+        proposal
+    } else {
+        panic!("Proposal not found");
+    }
+}
+
+/// Evaluate a batch grant voting power proposal
+/// If the quorum is reached, the proposal will be accepted and the voting power is granted
+/// to all candidates together once all of them consented within the consent period.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn evaluate(env: &Env, proposal_id: BytesN<32>) {
+    let proposal = read(env, proposal_id.clone());
+
+    let applicable = is_valid(env, &proposal);
+
+    if finalize_applicable(env, proposal_id.clone(), &proposal.metadata, applicable) {
+        await_consent(env, proposal_id);
+    }
+}
+
+/// Grant the voting power of an accepted batch grant voting power proposal after all candidates consented
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn apply(env: &Env, proposal_id: BytesN<32>) {
+    for candidate in read(env, proposal_id).candidates {
+        grant_voting_power::grant(env, candidate.clone());
+
+        event::grant_vote_power(env, candidate);
+    }
+}
@@ -0,0 +1,91 @@
+use super::*;
+
+/// Create a new proposal to remove members in one batch
+///
+/// # Arguments
+///
+/// - `proposal` - The proposal.
+pub fn write(env: &Env, proposal: types::proposal::BatchRemoveMember) -> BytesN<32> {
+    check(env, &proposal);
+
+    let proposal_id = create(
+        env,
+        types::proposal::Type::BatchRemoveMember,
+        &proposal.metadata,
+        &proposal,
+    );
+
+    ttl::set(
+        env,
+        &data_key::Proposal::BatchRemoveMember(proposal_id.clone()),
+        &proposal,
+    );
+
+    proposal_id
+}
+
+/// Panics if the proposal is not valid.
+/// The candidates of a proposal with dependencies are checked when it is evaluated instead,
+/// see [`is_valid`].
+fn check(env: &Env, proposal: &types::proposal::BatchRemoveMember) {
+    check_candidates(&proposal.candidates);
+
+    if proposal.metadata.dependencies.is_empty() {
+        for candidate in proposal.candidates.iter() {
+            if !member::is_member(env, candidate) {
+                panic!("Member does not exist");
+            }
+        }
+    }
+
+    if !keeps_min_members(env, proposal.candidates.len()) {
+        panic!("DAO must keep atleast min_members members");
+    }
+
+    if !keeps_min_voting_members(env, &proposal.candidates) {
+        panic!("DAO must keep atleast min_voting_members voting members");
+    }
+}
+
+/// Returns true if all candidates of the proposal can still be removed together when it is evaluated.
+pub(super) fn is_valid(env: &Env, proposal: &types::proposal::BatchRemoveMember) -> bool {
+    proposal
+        .candidates
+        .iter()
+        .all(|candidate| member::is_member(env, candidate))
+        && keeps_min_members(env, proposal.candidates.len())
+        && keeps_min_voting_members(env, &proposal.candidates)
+}
+
+/// Read a batch remove members proposal
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn read(env: &Env, proposal_id: BytesN<32>) -> types::proposal::BatchRemoveMember {
+    if let Some(proposal) = ttl::get(env, &data_key::Proposal::BatchRemoveMember(proposal_id)) {
+        proposal
+    } else {
+        panic!("Proposal not found");
+    }
+}
+
+/// Evaluate a batch remove members proposal
+/// If the quorum is reached, the proposal will be accepted and all candidates are removed together.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn evaluate(env: &Env, proposal_id: BytesN<32>) {
+    let proposal = read(env, proposal_id.clone());
+
+    // candidates may have left or been removed since the proposal was created
+    let applicable = is_valid(env, &proposal);
+
+    if finalize_applicable(env, proposal_id, &proposal.metadata, applicable) {
+        for candidate in proposal.candidates {
+            member::remove_member(env, candidate.clone());
+            event::remove_member(env, candidate);
+        }
+    }
+}
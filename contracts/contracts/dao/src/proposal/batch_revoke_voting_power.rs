@@ -0,0 +1,93 @@
+use super::*;
+
+/// Create a new proposal to revoke voting power from members in one batch
+///
+/// # Arguments
+///
+/// - `proposal` - The proposal.
+pub fn write(env: &Env, proposal: types::proposal::BatchRevokeVotingPower) -> BytesN<32> {
+    check(env, &proposal);
+
+    let proposal_id = create(
+        env,
+        types::proposal::Type::BatchRevokeVotingPower,
+        &proposal.metadata,
+        &proposal,
+    );
+
+    ttl::set(
+        env,
+        &data_key::Proposal::BatchRevokeVotingPower(proposal_id.clone()),
+        &proposal,
+    );
+
+    proposal_id
+}
+
+/// Panics if the proposal is not valid.
+/// The candidates of a proposal with dependencies are checked when it is evaluated instead,
+/// see [`is_valid`].
+fn check(env: &Env, proposal: &types::proposal::BatchRevokeVotingPower) {
+    check_candidates(&proposal.candidates);
+
+    if proposal.metadata.dependencies.is_empty() {
+        for candidate in proposal.candidates.iter() {
+            if !member::is_member(env, candidate.clone()) {
+                panic!("Member does not exist");
+            }
+
+            if !crate::has_voting_power(env, candidate) {
+                panic!("Already does not have voting power");
+            }
+        }
+    }
+
+    if !keeps_min_voting_members(env, &proposal.candidates) {
+        panic!("DAO must keep atleast min_voting_members voting members");
+    }
+}
+
+/// Returns true if the voting power of all candidates of the proposal can still be revoked together
+/// when it is evaluated.
+pub(super) fn is_valid(env: &Env, proposal: &types::proposal::BatchRevokeVotingPower) -> bool {
+    proposal.candidates.iter().all(|candidate| {
+        member::is_member(env, candidate.clone()) && crate::has_voting_power(env, candidate)
+    }) && keeps_min_voting_members(env, &proposal.candidates)
+}
+
+/// Read a batch revoke voting power proposal
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn read(env: &Env, proposal_id: BytesN<32>) -> types::proposal::BatchRevokeVotingPower {
+    if let Some(proposal) = ttl::get(
+        env,
+        &data_key::Proposal::BatchRevokeVotingPower(proposal_id),
+    ) {
+        proposal
+    } else {
+        panic!("Proposal not found");
+    }
+}
+
+/// Evaluate a batch revoke voting power proposal
+/// If the quorum is reached, the proposal will be accepted and the voting power of all candidates is revoked together.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn evaluate(env: &Env, proposal_id: BytesN<32>) {
+    let proposal = read(env, proposal_id.clone());
+
+    // candidates may have left or lost their voting power since the proposal was created
+    let applicable = is_valid(env, &proposal);
+
+    if finalize_applicable(env, proposal_id, &proposal.metadata, applicable) {
+        for candidate in proposal.candidates {
+            revoke_voting_power::revoke(env, candidate.clone());
+
+            event::revoke_vote_power(env, candidate);
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use super::*;
+
+use soroban_sdk::token;
+
+/// Escrows the bond of the DAO from the proposer of a new proposal.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+/// - `proposer` - The proposer of the proposal.
+pub(super) fn deposit(env: &Env, proposal_id: BytesN<32>, proposer: Address) {
+    let bond = metadata::dao::read(env).proposal_bond;
+
+    if let types::dao::Bond::Asset(asset, amount) = bond.clone() {
+        token::Client::new(env, &asset).transfer(
+            &proposer,
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        ttl::set(env, &data_key::Proposal::Bond(proposal_id), &bond);
+
+        write_escrow(env, asset.clone(), read_escrow(env, asset) + amount);
+    }
+}
+
+/// Settles the bond of a finalized proposal.
+/// It is refunded to the proposer if the proposal was accepted, or rejected with the minimum
+/// turnout of the DAO. Otherwise it is kept by the treasury.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+/// - `status` - The final status of the proposal.
+pub(super) fn settle(env: &Env, proposal_id: BytesN<32>, status: &types::proposal::Status) {
+    let key = data_key::Proposal::Bond(proposal_id.clone());
+
+    let (asset, amount) = match ttl::get(env, &key) {
+        Some(types::dao::Bond::Asset(asset, amount)) => (asset, amount),
+        _ => return,
+    };
+
+    env.storage().persistent().remove(&key);
+
+    write_escrow(env, asset.clone(), read_escrow(env, asset.clone()) - amount);
+
+    let refund = match status {
+        types::proposal::Status::Accepted => true,
+        types::proposal::Status::Rejected => reached_min_turnout(env, proposal_id.clone()),
+        _ => false,
+    };
+
+    if refund {
+        let proposer = metadata::proposal::read(env, proposal_id.clone()).proposer;
+
+        token::Client::new(env, &asset).transfer(
+            &env.current_contract_address(),
+            &proposer,
+            &amount,
+        );
+
+        event::bond_refunded(env, proposal_id, proposer, amount);
+    } else {
+        event::bond_forfeited(env, proposal_id, amount);
+    }
+}
+
+/// Returns the bond escrowed for a proposal, disabled if none is escrowed.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn read_bond(env: &Env, proposal_id: BytesN<32>) -> types::dao::Bond {
+    ttl::get(env, &data_key::Proposal::Bond(proposal_id)).unwrap_or(types::dao::Bond::Disabled)
+}
+
+/// Returns the balance of an asset held by the DAO which is not escrowed as proposal bonds.
+///
+/// # Arguments
+///
+/// - `asset` - The asset.
+pub fn treasury_balance(env: &Env, asset: Address) -> i128 {
+    token::Client::new(env, &asset).balance(&env.current_contract_address())
+        - read_escrow(env, asset)
+}
+
+fn read_escrow(env: &Env, asset: Address) -> i128 {
+    ttl::get(env, &data_key::Dao::BondEscrow(asset)).unwrap_or(0)
+}
+
+fn write_escrow(env: &Env, asset: Address, amount: i128) {
+    ttl::set(env, &data_key::Dao::BondEscrow(asset), &amount);
+}
+
+fn reached_min_turnout(env: &Env, proposal_id: BytesN<32>) -> bool {
+    let voters = role::count_voters(env, read_type(env, proposal_id.clone()));
+    let turnout = total_votes(env, proposal_id.clone()) + total_objections(env, proposal_id);
+
+    turnout * 100 >= voters * metadata::dao::read(env).bond_min_turnout_percentage
+}
@@ -0,0 +1,161 @@
+use super::*;
+
+/// Starts the consent period of an accepted proposal whose candidate has to agree to it.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub(super) fn await_consent(env: &Env, proposal_id: BytesN<32>) {
+    let consent_end = env.ledger().timestamp() + metadata::dao::read(env).consent_period;
+
+    ttl::set(
+        env,
+        &data_key::Proposal::ConsentEnd(proposal_id.clone()),
+        &consent_end,
+    );
+
+    event::awaiting_consent(env, proposal_id, consent_end);
+}
+
+/// Consents to an accepted add member or grant voting power proposal as one of its candidates.
+/// The proposal is carried out once all of its candidates consented, unless its candidates
+/// are no longer valid, in which case it lapses.
+///
+/// # Arguments
+///
+/// - `candidate` - The candidate of the proposal.
+/// - `proposal_id` - The id of the proposal.
+pub fn consent(env: &Env, candidate: Address, proposal_id: BytesN<32>) {
+    pause::only_unpaused(env);
+
+    let proposal_type = read_type(env, proposal_id.clone());
+
+    let candidates = read_candidates(env, proposal_id.clone(), proposal_type);
+
+    if candidates.is_empty() {
+        panic!("Proposal does not need consent");
+    }
+
+    if !candidates.contains(&candidate) {
+        panic!("Only a candidate can consent");
+    }
+
+    match read_status(env, proposal_id.clone()) {
+        types::proposal::Status::Accepted => {}
+        types::proposal::Status::Lapsed => panic!("Consent period has ended"),
+        _ => panic!("Proposal is not accepted"),
+    }
+
+    let key = data_key::Proposal::CandidateConsent(proposal_id.clone(), candidate.clone());
+
+    if env.storage().persistent().has(&key) {
+        panic!("Candidate has already consented");
+    }
+
+    ttl::set(env, &key, &true);
+
+    event::consent(env, proposal_id.clone(), candidate);
+
+    // the proposal is carried out once every candidate consented
+    for candidate in candidates.iter() {
+        let key = data_key::Proposal::CandidateConsent(proposal_id.clone(), candidate);
+
+        if !env.storage().persistent().has(&key) {
+            return;
+        }
+    }
+
+    // the candidates may have left the DAO while the proposal waited for their consent
+    if !is_applicable(env, proposal_id.clone(), proposal_type) {
+        write_status(env, proposal_id, types::proposal::Status::Lapsed);
+        return;
+    }
+
+    ttl::set(
+        env,
+        &data_key::Proposal::Consented(proposal_id.clone()),
+        &true,
+    );
+
+    match proposal_type {
+        types::proposal::Type::AddMember => add_member::apply(env, proposal_id),
+        types::proposal::Type::GrantVotingPower => grant_voting_power::apply(env, proposal_id),
+        types::proposal::Type::BatchAddMember => batch_add_member::apply(env, proposal_id),
+        _ => batch_grant_voting_power::apply(env, proposal_id),
+    }
+}
+
+/// Returns the candidates who have to consent to a proposal, empty if its type needs no consent.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+/// - `proposal_type` - The type of the proposal.
+pub fn read_candidates(
+    env: &Env,
+    proposal_id: BytesN<32>,
+    proposal_type: types::proposal::Type,
+) -> Vec<Address> {
+    match proposal_type {
+        types::proposal::Type::AddMember => {
+            vec![env, add_member::read(env, proposal_id).candidate]
+        }
+        types::proposal::Type::GrantVotingPower => {
+            vec![env, grant_voting_power::read(env, proposal_id).candidate]
+        }
+        types::proposal::Type::BatchAddMember => {
+            batch_add_member::read(env, proposal_id).candidates
+        }
+        types::proposal::Type::BatchGrantVotingPower => {
+            batch_grant_voting_power::read(env, proposal_id).candidates
+        }
+        _ => vec![env],
+    }
+}
+
+/// Returns true if the candidates of a proposal which needs consent are still valid.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+/// - `proposal_type` - The type of the proposal.
+fn is_applicable(env: &Env, proposal_id: BytesN<32>, proposal_type: types::proposal::Type) -> bool {
+    match proposal_type {
+        types::proposal::Type::GrantVotingPower => {
+            grant_voting_power::is_valid(env, &grant_voting_power::read(env, proposal_id))
+        }
+        types::proposal::Type::BatchGrantVotingPower => batch_grant_voting_power::is_valid(
+            env,
+            &batch_grant_voting_power::read(env, proposal_id),
+        ),
+        _ => true,
+    }
+}
+
+/// Returns true if all candidates of a proposal have consented to it.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn has_consented(env: &Env, proposal_id: BytesN<32>) -> bool {
+    env.storage()
+        .persistent()
+        .has(&data_key::Proposal::Consented(proposal_id))
+}
+
+/// Returns true if an accepted proposal waited for the consent of its candidate in vain.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn is_lapsed(env: &Env, proposal_id: BytesN<32>) -> bool {
+    let consent_end: Option<u64> =
+        ttl::get(env, &data_key::Proposal::ConsentEnd(proposal_id.clone()));
+
+    match consent_end {
+        Some(consent_end) => {
+            consent_end < env.ledger().timestamp() && !has_consented(env, proposal_id)
+        }
+        None => false,
+    }
+}
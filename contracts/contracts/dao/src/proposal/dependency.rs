@@ -0,0 +1,119 @@
+use super::*;
+
+/// The maximum number of dependencies of a proposal.
+pub const MAX_DEPENDENCIES: u32 = 10;
+
+/// Panics if a new proposal has too many dependencies, or one of them does not exist,
+/// has been pruned or is listed twice.
+///
+/// Dependencies cannot form a cycle: a dependency must exist when a proposal is created and the
+/// dependencies of a proposal cannot be amended, so every proposal only depends on older ones.
+///
+/// # Arguments
+///
+/// - `proposal_metadata` - The metadata of the new proposal.
+pub(super) fn check(env: &Env, proposal_metadata: &types::proposal::Metadata) {
+    let dependencies = &proposal_metadata.dependencies;
+
+    if dependencies.len() > MAX_DEPENDENCIES {
+        panic!("Proposal has too many dependencies");
+    }
+
+    for (index, dependency) in dependencies.iter().enumerate() {
+        // the type of a pruned proposal is removed with its other data
+        read_type(env, dependency.clone());
+
+        if dependencies.first_index_of(&dependency) != Some(index as u32) {
+            panic!("Duplicate dependency");
+        }
+    }
+}
+
+/// Records a new proposal as a dependent of each of its dependencies.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the new proposal.
+/// - `proposal_metadata` - The metadata of the new proposal.
+pub(super) fn record(
+    env: &Env,
+    proposal_id: BytesN<32>,
+    proposal_metadata: &types::proposal::Metadata,
+) {
+    for dependency in proposal_metadata.dependencies.iter() {
+        let mut dependents = read_dependents(env, dependency.clone());
+
+        dependents.push_back(proposal_id.clone());
+
+        ttl::set(
+            env,
+            &data_key::Proposal::Dependents(dependency),
+            &dependents,
+        );
+    }
+}
+
+/// Returns true if all dependencies of a proposal are accepted and applied, and false if one of
+/// them is rejected, vetoed or has lapsed. Panics if a dependency is not finalized or applied yet.
+///
+/// # Arguments
+///
+/// - `proposal_metadata` - The metadata of the proposal.
+pub(super) fn are_applied(env: &Env, proposal_metadata: &types::proposal::Metadata) -> bool {
+    for dependency in proposal_metadata.dependencies.iter() {
+        match read_status(env, dependency.clone()) {
+            types::proposal::Status::Accepted => {
+                if !is_applied(env, dependency) {
+                    panic!("Proposal dependencies are not applied yet");
+                }
+            }
+            types::proposal::Status::Rejected
+            | types::proposal::Status::Vetoed
+            | types::proposal::Status::Lapsed => return false,
+            _ => panic!("Proposal dependencies are not finalized yet"),
+        }
+    }
+
+    true
+}
+
+/// Returns the proposals which must be accepted and applied before a proposal can be evaluated.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn read_dependencies(env: &Env, proposal_id: BytesN<32>) -> Vec<BytesN<32>> {
+    metadata::proposal::read(env, proposal_id).dependencies
+}
+
+/// Returns the proposals which depend on a proposal.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn read_dependents(env: &Env, proposal_id: BytesN<32>) -> Vec<BytesN<32>> {
+    ttl::get(env, &data_key::Proposal::Dependents(proposal_id)).unwrap_or(Vec::new(env))
+}
+
+/// Returns true if an accepted proposal has been carried out: all of its candidates consented,
+/// or it has been executed if it is a spend proposal.
+fn is_applied(env: &Env, proposal_id: BytesN<32>) -> bool {
+    // accepted proposals are only pruned once they are applied
+    if is_pruned(env, proposal_id.clone()) {
+        return true;
+    }
+
+    if env
+        .storage()
+        .persistent()
+        .has(&data_key::Proposal::ConsentEnd(proposal_id.clone()))
+    {
+        return has_consented(env, proposal_id);
+    }
+
+    if read_type(env, proposal_id.clone()) == types::proposal::Type::Spend {
+        return spend::is_executed(env, proposal_id);
+    }
+
+    true
+}
@@ -6,20 +6,19 @@ use super::*;
 ///
 /// - `proposal` - The proposal.
 pub fn write(env: &Env, proposal: types::proposal::GeneralPurpose) -> BytesN<32> {
-    only_member_proposal(env, proposal.metadata.proposer.clone());
-    validate_proposal(env, &proposal.metadata);
-
-    let proposal_id = generate_unique_id(&env);
+    let proposal_id = create(
+        env,
+        types::proposal::Type::GeneralPurpose,
+        &proposal.metadata,
+        &proposal,
+    );
 
-    env.storage().persistent().set(
+    ttl::set(
+        env,
         &data_key::Proposal::GeneralPurpose(proposal_id.clone()),
         &proposal,
     );
 
-    metadata::proposal::write(&env, proposal_id.clone(), proposal.metadata.clone());
-
-    update_status(env, proposal_id.clone(), &proposal.metadata);
-
     proposal_id
 }
 
@@ -29,11 +28,7 @@ pub fn write(env: &Env, proposal: types::proposal::GeneralPurpose) -> BytesN<32>
 ///
 /// - `proposal_id` - The id of the proposal.
 pub fn read(env: &Env, proposal_id: BytesN<32>) -> types::proposal::GeneralPurpose {
-    if let Some(proposal) = env
-        .storage()
-        .persistent()
-        .get(&data_key::Proposal::GeneralPurpose(proposal_id))
-    {
+    if let Some(proposal) = ttl::get(env, &data_key::Proposal::GeneralPurpose(proposal_id)) {
         proposal
     } else {
         panic!("Proposal not found");
@@ -48,23 +43,7 @@ pub fn read(env: &Env, proposal_id: BytesN<32>) -> types::proposal::GeneralPurpo
 ///
 /// - `proposal_id` - The id of the proposal.
 pub fn evaluate(env: &Env, proposal_id: BytesN<32>) {
-    let status = read_status(env, proposal_id.clone());
-
-    if status == types::proposal::Status::Accepted || status == types::proposal::Status::Rejected {
-        panic!("Proposal already evaluated");
-    }
-
     let proposal = read(env, proposal_id.clone());
 
-    update_status(env, proposal_id.clone(), &proposal.metadata);
-
-    if read_status(env, proposal_id.clone()) != types::proposal::Status::Ended {
-        panic!("Proposal is not ended yet");
-    }
-
-    if is_quorum_reached(env, proposal_id.clone()) {
-        write_status(env, proposal_id.clone(), types::proposal::Status::Accepted);
-    } else {
-        write_status(env, proposal_id.clone(), types::proposal::Status::Rejected);
-    }
+    finalize(env, proposal_id, &proposal.metadata);
 }
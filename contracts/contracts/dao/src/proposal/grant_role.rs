@@ -0,0 +1,78 @@
+use super::*;
+
+/// Create a new proposal to grant a role to a member
+///
+/// # Arguments
+///
+/// - `proposal` - The proposal.
+pub fn write(env: &Env, proposal: types::proposal::GrantRole) -> BytesN<32> {
+    check(env, &proposal);
+
+    let proposal_id = create(
+        env,
+        types::proposal::Type::GrantRole,
+        &proposal.metadata,
+        &proposal,
+    );
+
+    ttl::set(
+        env,
+        &data_key::Proposal::GrantRole(proposal_id.clone()),
+        &proposal,
+    );
+
+    proposal_id
+}
+
+/// Panics if the candidate of the proposal is not valid.
+/// A proposal with dependencies is checked when it is evaluated instead, see [`is_valid`].
+pub(super) fn check(env: &Env, proposal: &types::proposal::GrantRole) {
+    if !proposal.metadata.dependencies.is_empty() {
+        return;
+    }
+
+    if !member::is_member(env, proposal.candidate.clone()) {
+        panic!("Member does not exist");
+    }
+
+    if role::has_role(env, proposal.candidate.clone(), proposal.role.clone()) {
+        panic!("Member already has role");
+    }
+}
+
+/// Returns true if the candidate of the proposal is still valid when it is evaluated.
+pub(super) fn is_valid(env: &Env, proposal: &types::proposal::GrantRole) -> bool {
+    member::is_member(env, proposal.candidate.clone())
+        && !role::has_role(env, proposal.candidate.clone(), proposal.role.clone())
+}
+
+/// Read a grant role proposal
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn read(env: &Env, proposal_id: BytesN<32>) -> types::proposal::GrantRole {
+    if let Some(proposal) = ttl::get(env, &data_key::Proposal::GrantRole(proposal_id)) {
+        proposal
+    } else {
+        panic!("Proposal not found");
+    }
+}
+
+/// Evaluate a grant role proposal
+/// If the quorum is reached, the proposal will be accepted and the role will be granted.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn evaluate(env: &Env, proposal_id: BytesN<32>) {
+    let proposal = read(env, proposal_id.clone());
+
+    let applicable = is_valid(env, &proposal);
+
+    if finalize_applicable(env, proposal_id, &proposal.metadata, applicable) {
+        role::grant_role(env, proposal.candidate.clone(), proposal.role.clone());
+
+        event::grant_role(env, proposal.candidate, proposal.role);
+    }
+}
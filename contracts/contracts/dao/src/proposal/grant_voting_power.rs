@@ -6,25 +6,44 @@ use super::*;
 ///
 /// - `proposal` - The proposal.
 pub fn write(env: &Env, proposal: types::proposal::GrantVotingPower) -> BytesN<32> {
-    if !member::is_member(env, proposal.candidate.clone()) {
-        panic!("Member does not exist");
-    }
-
-    only_member_proposal(env, proposal.metadata.proposer.clone());
-    validate_proposal(env, &proposal.metadata);
+    check(env, &proposal);
 
-    let proposal_id = generate_unique_id(&env);
+    let proposal_id = create(
+        env,
+        types::proposal::Type::GrantVotingPower,
+        &proposal.metadata,
+        &proposal,
+    );
 
-    env.storage().persistent().set(
+    ttl::set(
+        env,
         &data_key::Proposal::GrantVotingPower(proposal_id.clone()),
         &proposal,
     );
 
-    metadata::proposal::write(&env, proposal_id.clone(), proposal.metadata.clone());
+    proposal_id
+}
+
+/// Panics if the candidate of the proposal is not valid.
+/// A proposal with dependencies is checked when it is evaluated instead, see [`is_valid`].
+pub(super) fn check(env: &Env, proposal: &types::proposal::GrantVotingPower) {
+    if !proposal.metadata.dependencies.is_empty() {
+        return;
+    }
 
-    update_status(env, proposal_id.clone(), &proposal.metadata);
+    if !member::is_member(env, proposal.candidate.clone()) {
+        panic!("Member does not exist");
+    }
 
-    proposal_id
+    if crate::has_voting_power(env, proposal.candidate.clone()) {
+        panic!("Already has voting power");
+    }
+}
+
+/// Returns true if the candidate of the proposal is still valid when it is evaluated.
+pub(super) fn is_valid(env: &Env, proposal: &types::proposal::GrantVotingPower) -> bool {
+    member::is_member(env, proposal.candidate.clone())
+        && !crate::has_voting_power(env, proposal.candidate.clone())
 }
 
 /// Read a grant voting power proposal
@@ -33,11 +52,7 @@ pub fn write(env: &Env, proposal: types::proposal::GrantVotingPower) -> BytesN<3
 ///
 /// - `proposal_id` - The id of the proposal.
 pub fn read(env: &Env, proposal_id: BytesN<32>) -> types::proposal::GrantVotingPower {
-    if let Some(proposal) = env
-        .storage()
-        .persistent()
-        .get(&data_key::Proposal::GrantVotingPower(proposal_id))
-    {
+    if let Some(proposal) = ttl::get(env, &data_key::Proposal::GrantVotingPower(proposal_id)) {
         proposal
     } else {
         panic!("Proposal not found");
@@ -50,38 +65,40 @@ pub fn read(env: &Env, proposal_id: BytesN<32>) -> types::proposal::GrantVotingP
 ///
 /// - `member` - The member to whom voting power will be granted.
 pub fn grant(env: &Env, member: Address) {
-    dao_token::mint(env, member, 1_i128);
+    if crate::has_voting_power(env, member.clone()) {
+        panic!("Already has voting power");
+    }
+
+    dao_token::mint(env, member.clone(), 1_i128);
+    role::add_voter(env, member);
 }
 
 /// Evaluate a grant voting power proposal
-/// If the quorum is reached, the proposal will be accepted and the voting power will be granted.
+/// If the quorum is reached, the proposal will be accepted and the candidate has to consent
+/// within the consent period before the voting power is granted.
 ///
 /// # Arguments
 ///
 /// - `proposal_id` - The id of the proposal.
 pub fn evaluate(env: &Env, proposal_id: BytesN<32>) {
-    let status = read_status(env, proposal_id.clone());
-
-    if status == types::proposal::Status::Accepted || status == types::proposal::Status::Rejected {
-        panic!("Proposal already evaluated");
-    }
-
     let proposal = read(env, proposal_id.clone());
-    update_status(env, proposal_id.clone(), &proposal.metadata);
 
-    if read_status(env, proposal_id.clone()) != types::proposal::Status::Ended {
-        panic!("Proposal is not ended yet");
-    }
+    let applicable = is_valid(env, &proposal);
 
-    if is_quorum_reached(env, proposal_id.clone()) {
-        write_status(env, proposal_id.clone(), types::proposal::Status::Accepted);
+    if finalize_applicable(env, proposal_id.clone(), &proposal.metadata, applicable) {
+        await_consent(env, proposal_id);
+    }
+}
 
-        let candidate = proposal.candidate.clone();
+/// Grant the voting power of an accepted grant voting power proposal after the candidate consented
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn apply(env: &Env, proposal_id: BytesN<32>) {
+    let candidate = read(env, proposal_id).candidate;
 
-        grant(env, candidate.clone());
+    grant(env, candidate.clone());
 
-        event::grant_vote_power(env, candidate);
-    } else {
-        write_status(env, proposal_id.clone(), types::proposal::Status::Rejected);
-    }
+    event::grant_vote_power(env, candidate);
 }
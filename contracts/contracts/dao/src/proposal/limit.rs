@@ -0,0 +1,118 @@
+use super::*;
+
+/// Panics if the proposer already has the maximum number of open proposals of the DAO.
+///
+/// # Arguments
+///
+/// - `proposer` - The proposer of a new proposal.
+pub(super) fn check_open_limit(env: &Env, proposer: Address) {
+    let max_open_proposals = metadata::dao::read(env).max_open_proposals;
+
+    if max_open_proposals > 0 && read_open_proposals(env, proposer).len() >= max_open_proposals {
+        panic!("Proposer has too many open proposals");
+    }
+}
+
+/// Panics if the cooldown of the DAO since the last proposal of the proposer has not passed.
+///
+/// # Arguments
+///
+/// - `proposer` - The proposer of a new proposal.
+pub(super) fn check_cooldown(env: &Env, proposer: Address) {
+    if let Some(last_proposal) = read_last_proposal(env, proposer) {
+        if env.ledger().timestamp() < last_proposal + metadata::dao::read(env).proposal_cooldown {
+            panic!("Proposal cooldown has not passed yet");
+        }
+    }
+}
+
+/// Records a new proposal of a proposer for its open proposals and cooldown.
+/// Open proposals are only tracked while the DAO limits them.
+///
+/// # Arguments
+///
+/// - `proposer` - The proposer of the proposal.
+/// - `proposal_id` - The id of the proposal.
+pub(super) fn record(env: &Env, proposer: Address, proposal_id: BytesN<32>) {
+    if metadata::dao::read(env).max_open_proposals > 0 {
+        // closed proposals are dropped on every write so the list stays short
+        let mut open_proposals = read_open_proposals(env, proposer.clone());
+
+        open_proposals.push_back(proposal_id);
+
+        ttl::set(
+            env,
+            &data_key::Dao::OpenProposals(proposer.clone()),
+            &open_proposals,
+        );
+    }
+
+    ttl::set(
+        env,
+        &data_key::Dao::LastProposal(proposer),
+        &env.ledger().timestamp(),
+    );
+}
+
+/// Returns the proposals of a proposer which have not started or are still active.
+///
+/// # Arguments
+///
+/// - `proposer` - The proposer.
+pub fn read_open_proposals(env: &Env, proposer: Address) -> Vec<BytesN<32>> {
+    let proposals: Vec<BytesN<32>> =
+        ttl::get(env, &data_key::Dao::OpenProposals(proposer)).unwrap_or(Vec::new(env));
+
+    let mut open_proposals = Vec::new(env);
+
+    for proposal_id in proposals {
+        if matches!(
+            read_status(env, proposal_id.clone()),
+            types::proposal::Status::NotStarted
+                | types::proposal::Status::TemperatureCheck
+                | types::proposal::Status::Active
+        ) {
+            open_proposals.push_back(proposal_id);
+        }
+    }
+
+    open_proposals
+}
+
+/// Returns the number of open proposals of a proposer and the earliest time they may submit
+/// their next proposal.
+///
+/// # Arguments
+///
+/// - `proposer` - The proposer.
+pub fn read_proposer_status(env: &Env, proposer: Address) -> types::proposal::ProposerStatus {
+    let dao_metadata = metadata::dao::read(env);
+    let open_proposals = read_open_proposals(env, proposer.clone());
+
+    let mut next_proposal_at = match read_last_proposal(env, proposer) {
+        Some(last_proposal) => last_proposal + dao_metadata.proposal_cooldown,
+        None => 0,
+    };
+
+    // at the limit, the next proposal may be submitted once the first open one has ended
+    if dao_metadata.max_open_proposals > 0
+        && open_proposals.len() >= dao_metadata.max_open_proposals
+    {
+        let mut first_end = u64::MAX;
+
+        for proposal_id in open_proposals.iter() {
+            first_end = first_end.min(metadata::proposal::read(env, proposal_id).end_time + 1);
+        }
+
+        next_proposal_at = next_proposal_at.max(first_end);
+    }
+
+    types::proposal::ProposerStatus {
+        open_proposals: open_proposals.len(),
+        next_proposal_at,
+    }
+}
+
+fn read_last_proposal(env: &Env, proposer: Address) -> Option<u64> {
+    ttl::get(env, &data_key::Dao::LastProposal(proposer))
+}
@@ -0,0 +1,109 @@
+use super::*;
+
+/// Object to an optimistic proposal
+///
+/// # Arguments
+///
+/// - `objector` - The member who is objecting.
+/// - `proposal_id` - The id of the proposal.
+pub fn object(env: &Env, objector: Address, proposal_id: BytesN<32>) {
+    let proposal_metadata = check_voter(env, objector.clone(), proposal_id.clone());
+
+    if proposal_metadata.ballot != types::proposal::Ballot::Optimistic {
+        panic!("Proposal is not optimistic");
+    }
+
+    let objector_key = data_key::Proposal::Objector(proposal_id.clone(), objector.clone());
+
+    if env.storage().persistent().has(&objector_key) {
+        panic!("Member has already objected");
+    }
+
+    let count = total_objections(env, proposal_id.clone());
+
+    ttl::set(
+        env,
+        &data_key::Proposal::ObjectionAt(proposal_id.clone(), count),
+        &objector,
+    );
+    ttl::set(env, &objector_key, &count);
+    ttl::set(
+        env,
+        &data_key::Proposal::ObjectionCount(proposal_id.clone()),
+        &(count + 1),
+    );
+
+    event::object(env, proposal_id, objector);
+}
+
+/// Returns a page of the objectors to an optimistic proposal.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+/// - `offset` - The index of the first objector to return.
+/// - `limit` - The maximum number of objectors to return.
+pub fn read_objections(
+    env: &Env,
+    proposal_id: BytesN<32>,
+    offset: u32,
+    limit: u32,
+) -> Vec<Address> {
+    let end = offset
+        .saturating_add(limit)
+        .min(total_objections(env, proposal_id.clone()));
+
+    let mut objections = Vec::new(env);
+
+    for index in offset..end {
+        objections.push_back(
+            ttl::get(
+                env,
+                &data_key::Proposal::ObjectionAt(proposal_id.clone(), index),
+            )
+            .unwrap(),
+        );
+    }
+
+    objections
+}
+
+/// Returns the number of objections to an optimistic proposal.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn total_objections(env: &Env, proposal_id: BytesN<32>) -> u32 {
+    ttl::get(env, &data_key::Proposal::ObjectionCount(proposal_id)).unwrap_or(0)
+}
+
+/// Removes up to `limit` objections to a pruned proposal, the latest first.
+/// Returns the number of objections removed.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+/// - `limit` - The maximum number of objections to remove.
+pub(super) fn remove_objections(env: &Env, proposal_id: BytesN<32>, limit: u32) -> u32 {
+    let storage = env.storage().persistent();
+    let count = total_objections(env, proposal_id.clone());
+    let removed = limit.min(count);
+
+    for index in count - removed..count {
+        let key = data_key::Proposal::ObjectionAt(proposal_id.clone(), index);
+        let objector: Address = storage.get(&key).unwrap();
+
+        storage.remove(&data_key::Proposal::Objector(proposal_id.clone(), objector));
+        storage.remove(&key);
+    }
+
+    let key = data_key::Proposal::ObjectionCount(proposal_id);
+
+    if removed == count {
+        storage.remove(&key);
+    } else {
+        ttl::set(env, &key, &(count - removed));
+    }
+
+    removed
+}
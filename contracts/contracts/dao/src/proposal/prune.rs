@@ -0,0 +1,165 @@
+use super::*;
+
+/// Prunes a proposal finalized more than the prune delay of the DAO ago.
+/// Its summary is stored by the first call, which counts it as pruned. Every call removes up to
+/// `limit` of its votes, objections and commitments; its body, metadata and status are removed
+/// once none are left. Returns the number of votes, objections and commitments left to remove.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+/// - `limit` - The maximum number of votes, objections and commitments to remove.
+pub fn prune(env: &Env, proposal_id: BytesN<32>, limit: u32) -> u32 {
+    let proposal_type = if is_pruned(env, proposal_id.clone()) {
+        // a pruned proposal keeps its type until all of its data is removed
+        if !env
+            .storage()
+            .persistent()
+            .has(&data_key::Proposal::Type(proposal_id.clone()))
+        {
+            panic!("Proposal already pruned");
+        }
+
+        read_pruned_summary(env, proposal_id.clone()).proposal_type
+    } else {
+        write_summary(env, proposal_id.clone())
+    };
+
+    let mut removed = remove_objections(env, proposal_id.clone(), limit);
+    removed += remove_commitments(env, proposal_id.clone(), limit - removed);
+    remove_votes(env, proposal_id.clone(), limit - removed);
+
+    let remaining = total_objections(env, proposal_id.clone())
+        + total_commitments(env, proposal_id.clone())
+        + total_votes(env, proposal_id.clone());
+
+    if remaining == 0 {
+        remove_data(env, proposal_id, proposal_type);
+    }
+
+    remaining
+}
+
+/// Checks that a proposal can be pruned and stores its summary. Returns the type of the proposal.
+fn write_summary(env: &Env, proposal_id: BytesN<32>) -> types::proposal::Type {
+    let summary = read_summary(env, proposal_id.clone());
+
+    if summary.finalized_at == 0 {
+        panic!("Proposal is not finalized");
+    }
+
+    if summary.status == types::proposal::Status::Accepted {
+        let awaiting_consent = env
+            .storage()
+            .persistent()
+            .has(&data_key::Proposal::ConsentEnd(proposal_id.clone()))
+            && !has_consented(env, proposal_id.clone());
+
+        if awaiting_consent {
+            panic!("Proposal is awaiting consent");
+        }
+
+        if summary.proposal_type == types::proposal::Type::Spend
+            && !spend::is_executed(env, proposal_id.clone())
+        {
+            panic!("Spend proposal is not executed yet");
+        }
+    }
+
+    if env.ledger().timestamp() <= summary.finalized_at + metadata::dao::read(env).prune_delay {
+        panic!("Prune delay has not passed yet");
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&data_key::Proposal::BodyHash(summary.body_hash.clone()));
+
+    ttl::set(
+        env,
+        &data_key::Proposal::Summary(proposal_id.clone()),
+        &summary,
+    );
+
+    event::prune(env, proposal_id, summary.body_hash);
+
+    summary.proposal_type
+}
+
+/// Returns the summary of a proposal.
+/// The summary of a proposal which is not pruned yet is computed from its current data.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn read_summary(env: &Env, proposal_id: BytesN<32>) -> types::proposal::Summary {
+    if let Some(summary) = ttl::get(env, &data_key::Proposal::Summary(proposal_id.clone())) {
+        return summary;
+    }
+
+    let proposal_type = read_type(env, proposal_id.clone());
+
+    let body_hash = read_body_hash(env, proposal_type, proposal_id.clone());
+
+    let finalized_at =
+        ttl::get(env, &data_key::Proposal::FinalizedAt(proposal_id.clone())).unwrap_or(0);
+
+    types::proposal::Summary {
+        proposal_type,
+        status: read_status(env, proposal_id.clone()),
+        tally: read_tally(env, proposal_id.clone()),
+        votes: total_votes(env, proposal_id.clone()),
+        objections: total_objections(env, proposal_id),
+        body_hash,
+        finalized_at,
+    }
+}
+
+/// Returns true if the proposal has been pruned.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn is_pruned(env: &Env, proposal_id: BytesN<32>) -> bool {
+    env.storage()
+        .persistent()
+        .has(&data_key::Proposal::Summary(proposal_id))
+}
+
+/// Reads the summary of a pruned proposal.
+pub(super) fn read_pruned_summary(env: &Env, proposal_id: BytesN<32>) -> types::proposal::Summary {
+    if let Some(summary) = ttl::get(env, &data_key::Proposal::Summary(proposal_id)) {
+        summary
+    } else {
+        panic!("Proposal not found");
+    }
+}
+
+/// Removes the remaining data of a pruned proposal once its votes, objections and commitments are gone.
+fn remove_data(env: &Env, proposal_id: BytesN<32>, proposal_type: types::proposal::Type) {
+    let id = proposal_id;
+    let storage = env.storage().persistent();
+
+    for candidate in read_candidates(env, id.clone(), proposal_type) {
+        storage.remove(&data_key::Proposal::CandidateConsent(id.clone(), candidate));
+    }
+
+    // the type goes last, it marks a pruned proposal whose data is not removed yet
+    for key in [
+        body_key(proposal_type, id.clone()),
+        data_key::Proposal::Metadata(id.clone()),
+        data_key::Proposal::Status(id.clone()),
+        data_key::Proposal::Tally(id.clone()),
+        data_key::Proposal::GraceEnd(id.clone()),
+        data_key::Proposal::Executed(id.clone()),
+        data_key::Proposal::ConsentEnd(id.clone()),
+        data_key::Proposal::Consented(id.clone()),
+        data_key::Proposal::FinalizedAt(id.clone()),
+        data_key::Proposal::Revisions(id.clone()),
+        data_key::Proposal::Signal(id.clone()),
+        data_key::Proposal::TemperatureCheckPassed(id.clone()),
+        data_key::Proposal::Dependents(id.clone()),
+        data_key::Proposal::Type(id),
+    ] {
+        storage.remove(&key);
+    }
+}
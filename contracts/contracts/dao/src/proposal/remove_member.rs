@@ -6,25 +6,48 @@ use super::*;
 ///
 /// - `proposal` - The proposal.
 pub fn write(env: &Env, proposal: types::proposal::RemoveMember) -> BytesN<32> {
-    if !member::is_member(env, proposal.candidate.clone()) {
-        panic!("Member does not exist");
-    }
-
-    only_member_proposal(env, proposal.metadata.proposer.clone());
-    validate_proposal(env, &proposal.metadata);
+    check(env, &proposal);
 
-    let proposal_id = generate_unique_id(&env);
+    let proposal_id = create(
+        env,
+        types::proposal::Type::RemoveMember,
+        &proposal.metadata,
+        &proposal,
+    );
 
-    env.storage().persistent().set(
+    ttl::set(
+        env,
         &data_key::Proposal::RemoveMember(proposal_id.clone()),
         &proposal,
     );
 
-    metadata::proposal::write(&env, proposal_id.clone(), proposal.metadata.clone());
+    proposal_id
+}
 
-    update_status(env, proposal_id.clone(), &proposal.metadata);
+/// Panics if the proposal is not valid.
+/// The candidate of a proposal with dependencies is checked when it is evaluated instead,
+/// see [`is_valid`].
+pub(super) fn check(env: &Env, proposal: &types::proposal::RemoveMember) {
+    if proposal.metadata.dependencies.is_empty()
+        && !member::is_member(env, proposal.candidate.clone())
+    {
+        panic!("Member does not exist");
+    }
 
-    proposal_id
+    if !keeps_min_members(env, 1) {
+        panic!("DAO must keep atleast min_members members");
+    }
+
+    if !keeps_min_voting_members(env, &vec![env, proposal.candidate.clone()]) {
+        panic!("DAO must keep atleast min_voting_members voting members");
+    }
+}
+
+/// Returns true if the candidate of the proposal is still valid when it is evaluated.
+pub(super) fn is_valid(env: &Env, proposal: &types::proposal::RemoveMember) -> bool {
+    member::is_member(env, proposal.candidate.clone())
+        && keeps_min_members(env, 1)
+        && keeps_min_voting_members(env, &vec![env, proposal.candidate.clone()])
 }
 
 /// read a remove member proposal
@@ -33,11 +56,7 @@ pub fn write(env: &Env, proposal: types::proposal::RemoveMember) -> BytesN<32> {
 ///
 /// - `proposal_id` - The id of the proposal.
 pub fn read(env: &Env, proposal_id: BytesN<32>) -> types::proposal::RemoveMember {
-    if let Some(proposal) = env
-        .storage()
-        .persistent()
-        .get(&data_key::Proposal::RemoveMember(proposal_id))
-    {
+    if let Some(proposal) = ttl::get(env, &data_key::Proposal::RemoveMember(proposal_id)) {
         proposal
     } else {
         panic!("Proposal not found");
@@ -51,27 +70,15 @@ pub fn read(env: &Env, proposal_id: BytesN<32>) -> types::proposal::RemoveMember
 ///
 /// - `proposal_id` - The id of the proposal.
 pub fn evaluate(env: &Env, proposal_id: BytesN<32>) {
-    let status = read_status(env, proposal_id.clone());
-
-    if status == types::proposal::Status::Accepted || status == types::proposal::Status::Rejected {
-        panic!("Proposal already evaluated");
-    }
-
     let proposal = read(env, proposal_id.clone());
-    update_status(env, proposal_id.clone(), &proposal.metadata);
 
-    if read_status(env, proposal_id.clone()) != types::proposal::Status::Ended {
-        panic!("Proposal is not ended yet");
-    }
+    let candidate = proposal.candidate.clone();
 
-    if is_quorum_reached(env, proposal_id.clone()) {
-        write_status(env, proposal_id.clone(), types::proposal::Status::Accepted);
-
-        let candidate = proposal.candidate.clone();
+    // other removals may have been carried out since the proposal was created
+    let applicable = is_valid(env, &proposal);
 
+    if finalize_applicable(env, proposal_id, &proposal.metadata, applicable) {
         member::remove_member(env, candidate.clone());
         event::remove_member(env, candidate);
-    } else {
-        write_status(env, proposal_id.clone(), types::proposal::Status::Rejected);
     }
 }
@@ -6,25 +6,48 @@ use super::*;
 ///
 /// - `proposal` - The proposal.
 pub fn write(env: &Env, proposal: types::proposal::RevokeVotingPower) -> BytesN<32> {
-    if !member::is_member(env, proposal.candidate.clone()) {
-        panic!("Member does not exist");
-    }
-
-    only_member_proposal(env, proposal.metadata.proposer.clone());
-    validate_proposal(env, &proposal.metadata);
+    check(env, &proposal);
 
-    let proposal_id = generate_unique_id(&env);
+    let proposal_id = create(
+        env,
+        types::proposal::Type::RevokeVotingPower,
+        &proposal.metadata,
+        &proposal,
+    );
 
-    env.storage().persistent().set(
+    ttl::set(
+        env,
         &data_key::Proposal::RevokeVotingPower(proposal_id.clone()),
         &proposal,
     );
 
-    metadata::proposal::write(&env, proposal_id.clone(), proposal.metadata.clone());
+    proposal_id
+}
 
-    update_status(env, proposal_id.clone(), &proposal.metadata);
+/// Panics if the proposal is not valid.
+/// The candidate of a proposal with dependencies is checked when it is evaluated instead,
+/// see [`is_valid`].
+pub(super) fn check(env: &Env, proposal: &types::proposal::RevokeVotingPower) {
+    if proposal.metadata.dependencies.is_empty() {
+        if !member::is_member(env, proposal.candidate.clone()) {
+            panic!("Member does not exist");
+        }
+
+        if !crate::has_voting_power(env, proposal.candidate.clone()) {
+            panic!("Already does not have voting power");
+        }
+    }
 
-    proposal_id
+    if !keeps_min_voting_members(env, &vec![env, proposal.candidate.clone()]) {
+        panic!("DAO must keep atleast min_voting_members voting members");
+    }
+}
+
+/// Returns true if the candidate of the proposal is still valid when it is evaluated.
+pub(super) fn is_valid(env: &Env, proposal: &types::proposal::RevokeVotingPower) -> bool {
+    member::is_member(env, proposal.candidate.clone())
+        && crate::has_voting_power(env, proposal.candidate.clone())
+        && keeps_min_voting_members(env, &vec![env, proposal.candidate.clone()])
 }
 
 /// Read a revoke voting power proposal
@@ -33,11 +56,7 @@ pub fn write(env: &Env, proposal: types::proposal::RevokeVotingPower) -> BytesN<
 ///
 /// - `proposal_id` - The id of the proposal.
 pub fn read(env: &Env, proposal_id: BytesN<32>) -> types::proposal::RevokeVotingPower {
-    if let Some(proposal) = env
-        .storage()
-        .persistent()
-        .get(&data_key::Proposal::RevokeVotingPower(proposal_id))
-    {
+    if let Some(proposal) = ttl::get(env, &data_key::Proposal::RevokeVotingPower(proposal_id)) {
         proposal
     } else {
         panic!("Proposal not found");
@@ -50,7 +69,9 @@ pub fn read(env: &Env, proposal_id: BytesN<32>) -> types::proposal::RevokeVoting
 ///
 /// - `member` - The member who's voting power will be revoked.
 pub fn revoke(env: &Env, member: Address) {
-    dao_token::burn(env, member, 1_i128);
+    dao_token::burn(env, member.clone(), 1_i128);
+    role::remove_voter(env, member.clone());
+    pause::remove_votes(env, member);
 }
 
 /// Evaluate a revoke voting power proposal
@@ -60,26 +81,14 @@ pub fn revoke(env: &Env, member: Address) {
 ///
 /// - `proposal_id` - The id of the proposal.
 pub fn evaluate(env: &Env, proposal_id: BytesN<32>) {
-    let status = read_status(env, proposal_id.clone());
-
-    if status == types::proposal::Status::Accepted || status == types::proposal::Status::Rejected {
-        panic!("Proposal already evaluated");
-    }
-
     let proposal = read(env, proposal_id.clone());
-    update_status(env, proposal_id.clone(), &proposal.metadata);
-
-    if read_status(env, proposal_id.clone()) != types::proposal::Status::Ended {
-        panic!("Proposal is not ended yet");
-    }
 
-    if is_quorum_reached(env, proposal_id.clone()) {
-        write_status(env, proposal_id.clone(), types::proposal::Status::Accepted);
+    // other revocations may have been carried out since the proposal was created
+    let applicable = is_valid(env, &proposal);
 
+    if finalize_applicable(env, proposal_id, &proposal.metadata, applicable) {
         revoke(env, proposal.candidate.clone());
 
         event::revoke_vote_power(env, proposal.candidate.clone());
-    } else {
-        write_status(env, proposal_id.clone(), types::proposal::Status::Rejected);
     }
 }
@@ -0,0 +1,52 @@
+use super::*;
+
+/// Create a new proposal to replace the guardians of the DAO
+///
+/// # Arguments
+///
+/// - `proposal` - The proposal.
+pub fn write(env: &Env, proposal: types::proposal::SetGuardians) -> BytesN<32> {
+    let proposal_id = create(
+        env,
+        types::proposal::Type::SetGuardians,
+        &proposal.metadata,
+        &proposal,
+    );
+
+    ttl::set(
+        env,
+        &data_key::Proposal::SetGuardians(proposal_id.clone()),
+        &proposal,
+    );
+
+    proposal_id
+}
+
+/// Read a set guardians proposal
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn read(env: &Env, proposal_id: BytesN<32>) -> types::proposal::SetGuardians {
+    if let Some(proposal) = ttl::get(env, &data_key::Proposal::SetGuardians(proposal_id)) {
+        proposal
+    } else {
+        panic!("Proposal not found");
+    }
+}
+
+/// Evaluate a set guardians proposal
+/// If the quorum is reached, the proposal will be accepted and the guardians will be replaced.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn evaluate(env: &Env, proposal_id: BytesN<32>) {
+    let proposal = read(env, proposal_id.clone());
+
+    if finalize(env, proposal_id, &proposal.metadata) {
+        guardian::write_guardians(env, proposal.guardians.clone());
+
+        event::set_guardians(env, proposal.guardians);
+    }
+}
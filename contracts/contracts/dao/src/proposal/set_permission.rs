@@ -0,0 +1,52 @@
+use super::*;
+
+/// Create a new proposal to set the roles allowed to perform an action on a type of proposal
+///
+/// # Arguments
+///
+/// - `proposal` - The proposal.
+pub fn write(env: &Env, proposal: types::proposal::SetPermission) -> BytesN<32> {
+    let proposal_id = create(
+        env,
+        types::proposal::Type::SetPermission,
+        &proposal.metadata,
+        &proposal,
+    );
+
+    ttl::set(
+        env,
+        &data_key::Proposal::SetPermission(proposal_id.clone()),
+        &proposal,
+    );
+
+    proposal_id
+}
+
+/// Read a set permission proposal
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn read(env: &Env, proposal_id: BytesN<32>) -> types::proposal::SetPermission {
+    if let Some(proposal) = ttl::get(env, &data_key::Proposal::SetPermission(proposal_id)) {
+        proposal
+    } else {
+        panic!("Proposal not found");
+    }
+}
+
+/// Evaluate a set permission proposal
+/// If the quorum is reached, the proposal will be accepted and the permission will be replaced.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn evaluate(env: &Env, proposal_id: BytesN<32>) {
+    let proposal = read(env, proposal_id.clone());
+
+    if finalize(env, proposal_id, &proposal.metadata) {
+        role::write_permission(env, proposal.permission.clone());
+
+        event::set_permission(env, proposal.permission);
+    }
+}
@@ -0,0 +1,88 @@
+use super::*;
+
+/// Signals support for a proposal during its temperature check.
+/// Once enough of the members permitted to vote on it signalled, the proposal moves on to its
+/// binding vote at its start time.
+///
+/// # Arguments
+///
+/// - `voter` - The member signalling support.
+/// - `proposal_id` - The id of the proposal.
+pub fn signal(env: &Env, voter: Address, proposal_id: BytesN<32>) {
+    pause::only_unpaused(env);
+    only_member_vote(env, voter.clone(), proposal_id.clone());
+
+    if !crate::has_voting_power(env, voter.clone()) {
+        panic!("Voter does not have voting power");
+    }
+
+    let proposal_metadata = metadata::proposal::read(env, proposal_id.clone());
+
+    let percentage = match proposal_metadata.temperature_check {
+        types::proposal::TemperatureCheck::Signal(_, percentage) => percentage,
+        types::proposal::TemperatureCheck::Disabled => {
+            panic!("Proposal has no temperature check")
+        }
+    };
+
+    if is_finalized(env, proposal_id.clone()) {
+        panic!("Proposal already evaluated");
+    }
+
+    if timed_status(env, proposal_id.clone(), &proposal_metadata)
+        != types::proposal::Status::TemperatureCheck
+    {
+        panic!("Temperature check is not open");
+    }
+
+    let mut signals = read_signals(env, proposal_id.clone());
+
+    if signals.contains(&voter) {
+        panic!("Voter has already signalled");
+    }
+
+    signals.push_back(voter.clone());
+
+    ttl::set(
+        env,
+        &data_key::Proposal::Signal(proposal_id.clone()),
+        &signals,
+    );
+
+    event::signal(env, proposal_id.clone(), voter);
+
+    // the outcome is fixed when the threshold is reached, later membership changes do not undo it
+    let voters = role::count_voters(env, read_type(env, proposal_id.clone()));
+
+    if !has_passed_temperature_check(env, proposal_id.clone())
+        && signals.len() * 100 >= voters * percentage
+    {
+        ttl::set(
+            env,
+            &data_key::Proposal::TemperatureCheckPassed(proposal_id.clone()),
+            &true,
+        );
+
+        event::temperature_check_passed(env, proposal_id, signals.len());
+    }
+}
+
+/// Returns the members who signalled support for a proposal during its temperature check.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn read_signals(env: &Env, proposal_id: BytesN<32>) -> Vec<Address> {
+    ttl::get(env, &data_key::Proposal::Signal(proposal_id)).unwrap_or(Vec::new(env))
+}
+
+/// Returns true if enough voting members signalled support for a proposal.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn has_passed_temperature_check(env: &Env, proposal_id: BytesN<32>) -> bool {
+    env.storage()
+        .persistent()
+        .has(&data_key::Proposal::TemperatureCheckPassed(proposal_id))
+}
@@ -0,0 +1,182 @@
+use super::*;
+
+use soroban_sdk::token;
+
+/// Create a new proposal to spend a treasury asset
+///
+/// # Arguments
+///
+/// - `proposal` - The proposal.
+pub fn write(env: &Env, proposal: types::proposal::Spend) -> BytesN<32> {
+    if !metadata::dao::read(env)
+        .treasury_assets
+        .contains(&proposal.asset)
+    {
+        panic!("Asset is not a treasury asset");
+    }
+
+    if proposal.amount <= 0 {
+        panic!("Amount must be greater than zero");
+    }
+
+    let proposal_id = create(
+        env,
+        types::proposal::Type::Spend,
+        &proposal.metadata,
+        &proposal,
+    );
+
+    ttl::set(
+        env,
+        &data_key::Proposal::Spend(proposal_id.clone()),
+        &proposal,
+    );
+
+    proposal_id
+}
+
+/// Read a spend proposal
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn read(env: &Env, proposal_id: BytesN<32>) -> types::proposal::Spend {
+    if let Some(proposal) = ttl::get(env, &data_key::Proposal::Spend(proposal_id)) {
+        proposal
+    } else {
+        panic!("Proposal not found");
+    }
+}
+
+/// Evaluate a spend proposal
+/// If the quorum is reached, the proposal will be accepted and its grace period starts.
+/// The spend is executed with [`execute`] once the grace period has ended.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn evaluate(env: &Env, proposal_id: BytesN<32>) {
+    let proposal = read(env, proposal_id.clone());
+
+    if finalize(env, proposal_id.clone(), &proposal.metadata) {
+        let grace_end = env.ledger().timestamp() + metadata::dao::read(env).grace_period;
+
+        ttl::set(
+            env,
+            &data_key::Proposal::GraceEnd(proposal_id.clone()),
+            &grace_end,
+        );
+
+        event::spend_accepted(env, proposal_id, grace_end);
+    }
+}
+
+/// Execute an accepted spend proposal after its grace period by transferring the amount to the recipient.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn execute(env: &Env, proposal_id: BytesN<32>) {
+    pause::only_unpaused(env);
+
+    let proposal = read(env, proposal_id.clone());
+
+    check_unexecuted(env, proposal_id.clone());
+
+    if env.ledger().timestamp() <= read_grace_end(env, proposal_id.clone()) {
+        panic!("Grace period has not ended yet");
+    }
+
+    if !dependency::are_applied(env, &metadata::proposal::read(env, proposal_id.clone())) {
+        panic!("Proposal dependencies are not accepted");
+    }
+
+    // bonds escrowed for open proposals are not part of the treasury
+    if bond::treasury_balance(env, proposal.asset.clone()) < proposal.amount {
+        panic!("Insufficient treasury balance");
+    }
+
+    token::Client::new(env, &proposal.asset).transfer(
+        &env.current_contract_address(),
+        &proposal.recipient,
+        &proposal.amount,
+    );
+
+    ttl::set(
+        env,
+        &data_key::Proposal::Executed(proposal_id.clone()),
+        &true,
+    );
+
+    event::execute(env, proposal_id);
+}
+
+/// Checks if an accepted spend proposal has been executed.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn is_executed(env: &Env, proposal_id: BytesN<32>) -> bool {
+    env.storage()
+        .persistent()
+        .has(&data_key::Proposal::Executed(proposal_id))
+}
+
+/// Pays out the pro rata share of every treasury asset, excluding escrowed bonds, to a member who held voting power when
+/// a spend proposal was accepted, did not vote for it, and quits during its grace period.
+/// The caller is responsible for burning the voting power of the member and removing them.
+///
+/// # Arguments
+///
+/// - `member` - The member quitting the DAO.
+/// - `proposal_id` - The id of the accepted spend proposal.
+pub fn ragequit(env: &Env, member: Address, proposal_id: BytesN<32>) {
+    read(env, proposal_id.clone());
+
+    check_unexecuted(env, proposal_id.clone());
+
+    if env.ledger().timestamp() > read_grace_end(env, proposal_id.clone()) {
+        panic!("Grace period has ended");
+    }
+
+    if has_voted(env, proposal_id.clone(), member.clone()) {
+        panic!("Members who voted for the spend cannot ragequit");
+    }
+
+    let accepted_at: u64 = ttl::get(env, &data_key::Proposal::FinalizedAt(proposal_id)).unwrap();
+
+    match role::read_voter_since(env, member.clone()) {
+        Some(voter_since) if voter_since <= accepted_at => {}
+        Some(_) => panic!("Member was granted voting power after the spend was accepted"),
+        None => panic!("Member does not have voting power"),
+    }
+
+    let balance = dao_token::balance(env, member.clone());
+
+    let total_supply = dao_token::total_supply(env);
+
+    for asset in metadata::dao::read(env).treasury_assets {
+        let client = token::Client::new(env, &asset);
+
+        let share = bond::treasury_balance(env, asset) * balance / total_supply;
+
+        if share > 0 {
+            client.transfer(&env.current_contract_address(), &member, &share);
+        }
+    }
+}
+
+/// Panics if the spend proposal is not accepted or already executed.
+fn check_unexecuted(env: &Env, proposal_id: BytesN<32>) {
+    if read_status(env, proposal_id.clone()) != types::proposal::Status::Accepted {
+        panic!("Proposal is not accepted");
+    }
+
+    if is_executed(env, proposal_id) {
+        panic!("Proposal already executed");
+    }
+}
+
+fn read_grace_end(env: &Env, proposal_id: BytesN<32>) -> u64 {
+    ttl::get(env, &data_key::Proposal::GraceEnd(proposal_id)).unwrap()
+}
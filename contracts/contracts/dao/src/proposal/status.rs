@@ -1,49 +1,123 @@
 use super::*;
 
 /// Write the status of a proposal
+/// Records the current time and emits the outcome when the proposal is finalized.
 ///
 /// # Arguments
 ///
 /// - `proposal_id` - The id of the proposal.
 /// - `status` - The status of the proposal to write.
 pub fn write_status(env: &Env, proposal_id: BytesN<32>, status: types::proposal::Status) {
-    env.storage()
-        .persistent()
-        .set(&data_key::Proposal::Status(proposal_id), &status)
+    let old_status = read_status(env, proposal_id.clone());
+
+    ttl::set(
+        env,
+        &data_key::Proposal::Status(proposal_id.clone()),
+        &status,
+    );
+
+    event::status_changed(env, proposal_id.clone(), old_status, status.clone());
+
+    if matches!(
+        status,
+        types::proposal::Status::Accepted
+            | types::proposal::Status::Rejected
+            | types::proposal::Status::Vetoed
+    ) {
+        ttl::set(
+            env,
+            &data_key::Proposal::FinalizedAt(proposal_id.clone()),
+            &env.ledger().timestamp(),
+        );
+
+        bond::settle(env, proposal_id.clone(), &status);
+
+        event::proposal_finalized(
+            env,
+            proposal_id.clone(),
+            status,
+            read_tally(env, proposal_id.clone()),
+            total_votes(env, proposal_id.clone()),
+            total_objections(env, proposal_id),
+        );
+    }
 }
 
 /// Read the status of a proposal
+/// The status of a proposal which is not finalized yet is computed from its start and end time.
+/// An accepted proposal whose candidate did not consent in time is reported as lapsed.
+/// The status of a pruned proposal is read from its summary.
 ///
 /// # Arguments
 ///
 /// - `proposal_id` - The id of the proposal.
 pub fn read_status(env: &Env, proposal_id: BytesN<32>) -> types::proposal::Status {
-    env.storage()
-        .persistent()
-        .get(&data_key::Proposal::Status(proposal_id))
-        .unwrap()
+    let status = ttl::get(env, &data_key::Proposal::Status(proposal_id.clone()));
+
+    match status {
+        Some(types::proposal::Status::Accepted) if is_lapsed(env, proposal_id.clone()) => {
+            types::proposal::Status::Lapsed
+        }
+        Some(status) => status,
+        None if is_pruned(env, proposal_id.clone()) => read_pruned_summary(env, proposal_id).status,
+        None => timed_status(
+            env,
+            proposal_id.clone(),
+            &metadata::proposal::read(env, proposal_id),
+        ),
+    }
+}
+
+/// Returns true if the proposal has been accepted, rejected, vetoed or has lapsed.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn is_finalized(env: &Env, proposal_id: BytesN<32>) -> bool {
+    matches!(
+        read_status(env, proposal_id),
+        types::proposal::Status::Accepted
+            | types::proposal::Status::Rejected
+            | types::proposal::Status::Vetoed
+            | types::proposal::Status::Lapsed
+    )
 }
 
-/// Update the status of a proposal according to the current time
+/// Returns the status of a proposal which is not finalized according to the current time.
+/// A proposal with a temperature check only reaches its voting window if the check passed.
 ///
 /// # Arguments
 ///
 /// - `proposal_id` - The id of the proposal.
 /// - `proposal_metadata` - The metadata of the proposal.
-pub fn update_status(
+pub fn timed_status(
     env: &Env,
     proposal_id: BytesN<32>,
     proposal_metadata: &types::proposal::Metadata,
-) {
+) -> types::proposal::Status {
+    if let types::proposal::TemperatureCheck::Signal(duration, _) =
+        proposal_metadata.temperature_check
+    {
+        let now = env.ledger().timestamp();
+
+        if now < proposal_metadata.start_time {
+            return if now < proposal_metadata.start_time - duration {
+                types::proposal::Status::NotStarted
+            } else {
+                types::proposal::Status::TemperatureCheck
+            };
+        }
+
+        if !has_passed_temperature_check(env, proposal_id) {
+            return types::proposal::Status::TemperatureCheckFailed;
+        }
+    }
+
     if proposal_metadata.end_time < env.ledger().timestamp() {
-        write_status(env, proposal_id.clone(), types::proposal::Status::Ended);
+        types::proposal::Status::Ended
     } else if proposal_metadata.start_time > env.ledger().timestamp() {
-        write_status(
-            env,
-            proposal_id.clone(),
-            types::proposal::Status::NotStarted,
-        );
+        types::proposal::Status::NotStarted
     } else {
-        write_status(env, proposal_id.clone(), types::proposal::Status::Active);
+        types::proposal::Status::Active
     }
 }
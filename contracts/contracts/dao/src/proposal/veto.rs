@@ -0,0 +1,45 @@
+use super::*;
+
+/// Veto a proposal as a guardian of the DAO.
+/// Proposals that are not evaluated yet can be vetoed, as well as accepted general purpose
+/// proposals since their actions are carried out by the community after the fact, and
+/// accepted spend proposals which have not been executed yet.
+/// Proposals to set the guardians cannot be vetoed, so guardians cannot block their own replacement.
+///
+/// # Arguments
+///
+/// - `guardian` - The guardian vetoing the proposal.
+/// - `proposal_id` - The id of the proposal.
+pub fn veto(env: &Env, guardian: Address, proposal_id: BytesN<32>) {
+    if !guardian::is_guardian(env, guardian.clone()) {
+        panic!("Only guardians can veto proposals");
+    }
+
+    if read_type(env, proposal_id.clone()) == types::proposal::Type::SetGuardians {
+        panic!("Guardians cannot veto proposals to set the guardians");
+    }
+
+    match read_status(env, proposal_id.clone()) {
+        types::proposal::Status::NotStarted
+        | types::proposal::Status::TemperatureCheck
+        | types::proposal::Status::TemperatureCheckFailed
+        | types::proposal::Status::Active
+        | types::proposal::Status::Ended => {}
+        types::proposal::Status::Accepted
+            if env
+                .storage()
+                .persistent()
+                .has(&data_key::Proposal::GeneralPurpose(proposal_id.clone())) => {}
+        types::proposal::Status::Accepted
+            if env
+                .storage()
+                .persistent()
+                .has(&data_key::Proposal::Spend(proposal_id.clone()))
+                && !spend::is_executed(env, proposal_id.clone()) => {}
+        _ => panic!("Proposal can no longer be vetoed"),
+    }
+
+    write_status(env, proposal_id.clone(), types::proposal::Status::Vetoed);
+
+    event::veto(env, proposal_id, guardian);
+}
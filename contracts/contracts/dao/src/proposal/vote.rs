@@ -7,20 +7,219 @@ use super::*;
 /// - `voter` - The voter who is voting.
 /// - `proposal_id` - The id of the proposal.
 pub fn vote(env: &Env, voter: Address, proposal_id: BytesN<32>) {
-    only_member_vote(env, voter.clone());
+    if let types::proposal::VotingMode::Quadratic(_) = read_voting_mode(env, proposal_id.clone()) {
+        panic!("Proposal uses quadratic voting");
+    }
 
-    if !crate::has_voting_power(&env, voter.clone()) {
-        panic!("Voter does not have voting power");
+    cast_vote(env, voter, proposal_id);
+}
+
+/// Vote on a quadratic voting proposal by spending voice credits.
+/// The vote counts as the square root of the credits spent.
+///
+/// # Arguments
+///
+/// - `voter` - The voter who is voting.
+/// - `proposal_id` - The id of the proposal.
+/// - `credits` - The voice credits to spend.
+pub fn quadratic_vote(env: &Env, voter: Address, proposal_id: BytesN<32>, credits: u32) {
+    let voice_credits = match read_voting_mode(env, proposal_id.clone()) {
+        types::proposal::VotingMode::Quadratic(voice_credits) => voice_credits,
+        _ => panic!("Proposal does not use quadratic voting"),
+    };
+
+    cast_vote(env, voter.clone(), proposal_id.clone());
+
+    if credits == 0 {
+        panic!("Voice credits must be greater than zero");
     }
 
-    let proposal_metadata = metadata::proposal::read(env, proposal_id.clone());
+    if credits > voice_credit_budget(env, &voice_credits, voter.clone()) {
+        panic!("Not enough voice credits");
+    }
+
+    ttl::set(
+        env,
+        &data_key::Proposal::VoiceCredits(proposal_id.clone(), voter),
+        &credits,
+    );
 
-    check_proposal(env, proposal_id.clone(), &proposal_metadata);
+    let tally = read_tally(env, proposal_id.clone()) + isqrt(credits);
+
+    ttl::set(env, &data_key::Proposal::Tally(proposal_id), &tally);
+}
+
+/// Checks that the voter may vote on the proposal and records the vote.
+///
+/// # Arguments
+///
+/// - `voter` - The voter who is voting.
+/// - `proposal_id` - The id of the proposal.
+fn cast_vote(env: &Env, voter: Address, proposal_id: BytesN<32>) {
+    let proposal_metadata = check_voter(env, voter.clone(), proposal_id.clone());
+
+    match proposal_metadata.ballot {
+        types::proposal::Ballot::Secret(_) => panic!("Proposal uses a secret ballot"),
+        types::proposal::Ballot::Optimistic => {
+            panic!("Optimistic proposals take objections, not votes")
+        }
+        types::proposal::Ballot::Open => {}
+    }
 
     write_vote(env, proposal_id.clone(), voter.clone());
     event::vote(env, proposal_id, voter);
 }
 
+/// Checks that the voter may vote on the proposal right now and returns the proposal metadata.
+pub(super) fn check_voter(
+    env: &Env,
+    voter: Address,
+    proposal_id: BytesN<32>,
+) -> types::proposal::Metadata {
+    pause::only_unpaused(env);
+    only_voter(env, voter, proposal_id.clone());
+
+    let proposal_metadata = metadata::proposal::read(env, proposal_id.clone());
+
+    check_proposal(env, proposal_id, &proposal_metadata);
+
+    proposal_metadata
+}
+
+/// Panics if the voter is not a member with voting power and a role allowed to vote on the proposal.
+fn only_voter(env: &Env, voter: Address, proposal_id: BytesN<32>) {
+    only_member_vote(env, voter.clone(), proposal_id);
+
+    if !crate::has_voting_power(env, voter) {
+        panic!("Voter does not have voting power");
+    }
+}
+
+/// Commits a secret vote on a proposal.
+///
+/// # Arguments
+///
+/// - `voter` - The voter who is voting.
+/// - `proposal_id` - The id of the proposal.
+/// - `commitment` - `sha256(choice || salt)`, see [`commitment_hash`].
+pub fn commit_vote(env: &Env, voter: Address, proposal_id: BytesN<32>, commitment: BytesN<32>) {
+    let proposal_metadata = check_voter(env, voter.clone(), proposal_id.clone());
+
+    if !matches!(proposal_metadata.ballot, types::proposal::Ballot::Secret(_)) {
+        panic!("Proposal does not use a secret ballot");
+    }
+
+    let key = data_key::Proposal::Commitment(proposal_id.clone(), voter.clone());
+
+    if env.storage().persistent().has(&key) {
+        panic!("Voter has already committed");
+    }
+
+    ttl::set(env, &key, &commitment);
+
+    let count = total_commitments(env, proposal_id.clone());
+
+    ttl::set(
+        env,
+        &data_key::Proposal::CommitmentAt(proposal_id.clone(), count),
+        &voter,
+    );
+    ttl::set(
+        env,
+        &data_key::Proposal::CommitmentCount(proposal_id.clone()),
+        &(count + 1),
+    );
+
+    event::commit(env, proposal_id, voter);
+}
+
+/// Reveals a secret vote on a proposal. Only revealed votes in favour are counted.
+///
+/// # Arguments
+///
+/// - `voter` - The voter who committed the vote.
+/// - `proposal_id` - The id of the proposal.
+/// - `choice` - The committed choice, `true` votes in favour of the proposal.
+/// - `salt` - The salt used for the commitment.
+pub fn reveal_vote(
+    env: &Env,
+    voter: Address,
+    proposal_id: BytesN<32>,
+    choice: bool,
+    salt: BytesN<32>,
+) {
+    pause::only_unpaused(env);
+
+    // the voter may have left or lost their voting power since committing
+    only_voter(env, voter.clone(), proposal_id.clone());
+
+    let proposal_metadata = metadata::proposal::read(env, proposal_id.clone());
+
+    let reveal_duration = match proposal_metadata.ballot {
+        types::proposal::Ballot::Secret(reveal_duration) => reveal_duration,
+        _ => panic!("Proposal does not use a secret ballot"),
+    };
+
+    let now = env.ledger().timestamp();
+
+    if now <= proposal_metadata.end_time {
+        panic!("Reveal phase has not started");
+    } else if now > proposal_metadata.end_time + reveal_duration {
+        panic!("Reveal phase has ended");
+    }
+
+    let key = data_key::Proposal::Commitment(proposal_id.clone(), voter.clone());
+
+    let commitment: BytesN<32> = if let Some(commitment) = ttl::get(env, &key) {
+        commitment
+    } else {
+        panic!("Commitment not found");
+    };
+
+    if commitment != commitment_hash(env, choice, salt) {
+        panic!("Commitment does not match");
+    }
+
+    env.storage().persistent().remove(&key);
+
+    if choice {
+        write_vote(env, proposal_id.clone(), voter.clone());
+    }
+
+    event::reveal(env, proposal_id, voter);
+}
+
+/// Returns the commitment for a secret vote, `sha256(choice || salt)` where `choice` is a
+/// single byte that is `1` in favour and `0` against.
+///
+/// # Arguments
+///
+/// - `choice` - The choice to commit to.
+/// - `salt` - A secret random salt.
+pub fn commitment_hash(env: &Env, choice: bool, salt: BytesN<32>) -> BytesN<32> {
+    let mut payload = Bytes::new(env);
+
+    payload.push_back(choice as u8);
+    payload.append(&salt.into());
+
+    env.crypto().sha256(&payload)
+}
+
+/// Panics if the votes of a secret ballot proposal are read before its reveal phase has ended.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn check_votes_unsealed(env: &Env, proposal_id: BytesN<32>) {
+    let proposal_metadata = metadata::proposal::read(env, proposal_id);
+
+    if let types::proposal::Ballot::Secret(reveal_duration) = proposal_metadata.ballot {
+        if proposal_metadata.end_time + reveal_duration >= env.ledger().timestamp() {
+            panic!("Votes are sealed until the reveal phase ends");
+        }
+    }
+}
+
 /// Writes a vote to the proposal.
 ///
 /// # Arguments
@@ -28,27 +227,349 @@ pub fn vote(env: &Env, voter: Address, proposal_id: BytesN<32>) {
 /// - `proposal_id` - The id of the proposal.
 /// - `voter` - The voter who is voting.
 fn write_vote(env: &Env, proposal_id: BytesN<32>, voter: Address) {
-    let mut votes = read_votes(env, proposal_id.clone());
-
-    if votes.contains(&voter) {
+    if has_voted(env, proposal_id.clone(), voter.clone()) {
         panic!("Voter has already voted");
     }
 
-    votes.push_back(voter.clone());
+    let count = read_vote_count(env, proposal_id.clone());
+
+    write_vote_at(env, proposal_id.clone(), count, voter);
+    write_vote_count(env, proposal_id, count + 1);
+}
+
+/// Checks if a voter has voted on a proposal.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+/// - `voter` - The voter.
+pub fn has_voted(env: &Env, proposal_id: BytesN<32>, voter: Address) -> bool {
+    env.storage().persistent().has(&data_key::Proposal::Voter(
+        proposal_id.clone(),
+        voter.clone(),
+    )) || read_legacy_votes(env, proposal_id).contains(&voter)
+}
+
+/// Returns a page of the votes of a proposal.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+/// - `offset` - The index of the first vote to read.
+/// - `limit` - The maximum number of votes to read.
+pub fn read_votes(env: &Env, proposal_id: BytesN<32>, offset: u32, limit: u32) -> Vec<Address> {
+    let count = read_vote_count(env, proposal_id.clone());
+    let legacy_votes = read_legacy_votes(env, proposal_id.clone());
+    let end = offset.saturating_add(limit).min(count + legacy_votes.len());
+
+    let mut votes = Vec::new(env);
+
+    for index in offset..end {
+        if index < count {
+            votes.push_back(
+                ttl::get(env, &data_key::Proposal::VoteAt(proposal_id.clone(), index)).unwrap(),
+            );
+        } else {
+            votes.push_back(legacy_votes.get(index - count).unwrap());
+        }
+    }
+
+    votes
+}
+
+/// Returns the voters who committed a secret vote on a proposal, including those who revealed it.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+/// - `offset` - The index of the first voter to return.
+/// - `limit` - The maximum number of voters to return.
+pub fn read_committers(
+    env: &Env,
+    proposal_id: BytesN<32>,
+    offset: u32,
+    limit: u32,
+) -> Vec<Address> {
+    let end = offset
+        .saturating_add(limit)
+        .min(total_commitments(env, proposal_id.clone()));
+
+    let mut committers = Vec::new(env);
+
+    for index in offset..end {
+        committers.push_back(
+            ttl::get(
+                env,
+                &data_key::Proposal::CommitmentAt(proposal_id.clone(), index),
+            )
+            .unwrap(),
+        );
+    }
+
+    committers
+}
+
+/// Returns the number of secret votes committed on a proposal.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn total_commitments(env: &Env, proposal_id: BytesN<32>) -> u32 {
+    ttl::get(env, &data_key::Proposal::CommitmentCount(proposal_id)).unwrap_or(0)
+}
+
+/// Returns the number of votes of a proposal.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn total_votes(env: &Env, proposal_id: BytesN<32>) -> u32 {
+    read_vote_count(env, proposal_id.clone()) + read_legacy_votes(env, proposal_id).len()
+}
+
+/// Moves up to `limit` votes of a proposal from the legacy votes vector into per-vote storage.
+/// Returns the number of votes still left to migrate.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+/// - `limit` - The maximum number of votes to migrate.
+pub fn migrate_votes(env: &Env, proposal_id: BytesN<32>, limit: u32) -> u32 {
+    let key = data_key::Proposal::Vote(proposal_id.clone());
+
+    if !env.storage().persistent().has(&key) {
+        return 0;
+    }
+
+    let mut legacy_votes = read_legacy_votes(env, proposal_id.clone());
+    let mut count = read_vote_count(env, proposal_id.clone());
+
+    for _ in 0..limit.min(legacy_votes.len()) {
+        let voter = legacy_votes.pop_front().unwrap();
+
+        write_vote_at(env, proposal_id.clone(), count, voter);
+        count += 1;
+    }
+
+    write_vote_count(env, proposal_id, count);
+
+    let remaining = legacy_votes.len();
+
+    if remaining == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        ttl::set(env, &key, &legacy_votes);
+    }
+
+    remaining
+}
+
+/// Removes up to `limit` votes on a pruned proposal, the latest first.
+/// Returns the number of votes removed.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+/// - `limit` - The maximum number of votes to remove.
+pub(super) fn remove_votes(env: &Env, proposal_id: BytesN<32>, limit: u32) -> u32 {
+    let storage = env.storage().persistent();
+    let mut legacy_votes = read_legacy_votes(env, proposal_id.clone());
+    let mut count = read_vote_count(env, proposal_id.clone());
+    let mut removed = 0;
+
+    // legacy votes come after the migrated ones
+    while removed < limit {
+        let voter = if let Some(voter) = legacy_votes.pop_back() {
+            voter
+        } else if count > 0 {
+            count -= 1;
+
+            let key = data_key::Proposal::VoteAt(proposal_id.clone(), count);
+            let voter: Address = storage.get(&key).unwrap();
+            storage.remove(&key);
+
+            voter
+        } else {
+            break;
+        };
+
+        storage.remove(&data_key::Proposal::Voter(
+            proposal_id.clone(),
+            voter.clone(),
+        ));
+        storage.remove(&data_key::Proposal::VoiceCredits(
+            proposal_id.clone(),
+            voter,
+        ));
+        removed += 1;
+    }
+
+    let key = data_key::Proposal::Vote(proposal_id.clone());
+
+    if legacy_votes.is_empty() {
+        storage.remove(&key);
+    } else {
+        ttl::set(env, &key, &legacy_votes);
+    }
+
+    if count == 0 {
+        storage.remove(&data_key::Proposal::VoteCount(proposal_id));
+    } else {
+        write_vote_count(env, proposal_id, count);
+    }
+
+    removed
+}
+
+/// Removes up to `limit` secret votes committed on a pruned proposal, the latest first.
+/// Returns the number of commitments removed.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+/// - `limit` - The maximum number of commitments to remove.
+pub(super) fn remove_commitments(env: &Env, proposal_id: BytesN<32>, limit: u32) -> u32 {
+    let storage = env.storage().persistent();
+    let count = total_commitments(env, proposal_id.clone());
+    let removed = limit.min(count);
+
+    for index in count - removed..count {
+        let key = data_key::Proposal::CommitmentAt(proposal_id.clone(), index);
+        let voter: Address = storage.get(&key).unwrap();
+
+        storage.remove(&data_key::Proposal::Commitment(proposal_id.clone(), voter));
+        storage.remove(&key);
+    }
+
+    let key = data_key::Proposal::CommitmentCount(proposal_id);
+
+    if removed == count {
+        storage.remove(&key);
+    } else {
+        ttl::set(env, &key, &(count - removed));
+    }
+
+    removed
+}
+
+fn read_vote_count(env: &Env, proposal_id: BytesN<32>) -> u32 {
+    ttl::get(env, &data_key::Proposal::VoteCount(proposal_id)).unwrap_or(0)
+}
+
+fn write_vote_count(env: &Env, proposal_id: BytesN<32>, count: u32) {
+    ttl::set(env, &data_key::Proposal::VoteCount(proposal_id), &count);
+}
+
+fn write_vote_at(env: &Env, proposal_id: BytesN<32>, index: u32, voter: Address) {
+    ttl::set(
+        env,
+        &data_key::Proposal::VoteAt(proposal_id.clone(), index),
+        &voter,
+    );
+    ttl::set(env, &data_key::Proposal::Voter(proposal_id, voter), &index);
+}
+
+fn read_legacy_votes(env: &Env, proposal_id: BytesN<32>) -> Vec<Address> {
+    ttl::get(env, &data_key::Proposal::Vote(proposal_id)).unwrap_or(Vec::new(env))
+}
 
-    env.storage()
-        .persistent()
-        .set(&data_key::Proposal::Vote(proposal_id), &votes);
+/// Returns the voice credits a voter spent on a quadratic voting proposal.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+/// - `voter` - The voter.
+pub fn read_voice_credits(env: &Env, proposal_id: BytesN<32>, voter: Address) -> u32 {
+    ttl::get(env, &data_key::Proposal::VoiceCredits(proposal_id, voter)).unwrap_or(0)
 }
 
-/// Returns the votes of a proposal.
+/// Returns the voting mode of a proposal, falling back to the voting mode of the DAO.
 ///
 /// # Arguments
 ///
 /// - `proposal_id` - The id of the proposal.
-pub fn read_votes(env: &Env, proposal_id: BytesN<32>) -> Vec<Address> {
-    env.storage()
-        .persistent()
-        .get(&data_key::Proposal::Vote(proposal_id))
-        .unwrap_or(Vec::new(env))
+pub fn read_voting_mode(env: &Env, proposal_id: BytesN<32>) -> types::proposal::VotingMode {
+    match metadata::proposal::read(env, proposal_id).voting_mode {
+        types::proposal::VotingMode::DaoDefault => metadata::dao::read(env).voting_mode,
+        voting_mode => voting_mode,
+    }
+}
+
+/// Returns the effective votes of a proposal.
+/// For quadratic voting this is the sum of the square roots of the credits spent.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn read_tally(env: &Env, proposal_id: BytesN<32>) -> u32 {
+    match read_voting_mode(env, proposal_id.clone()) {
+        types::proposal::VotingMode::Quadratic(_) => {
+            ttl::get(env, &data_key::Proposal::Tally(proposal_id)).unwrap_or(0)
+        }
+        _ => total_votes(env, proposal_id),
+    }
+}
+
+/// Returns the effective votes of a proposal if every member permitted to vote on it voted
+/// with their whole budget.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+pub fn max_tally(env: &Env, proposal_id: BytesN<32>) -> u32 {
+    let voting_mode = read_voting_mode(env, proposal_id.clone());
+    let voters = role::count_voters(env, read_type(env, proposal_id));
+
+    voters.saturating_mul(max_votes(&voting_mode))
+}
+
+/// Returns the effective votes of a voter if they spend their whole voice credit budget.
+/// Every voter holds exactly one dao token, so all voters have the same budget.
+///
+/// # Arguments
+///
+/// - `voting_mode` - The voting mode of the proposal.
+pub fn max_votes(voting_mode: &types::proposal::VotingMode) -> u32 {
+    match voting_mode {
+        types::proposal::VotingMode::Quadratic(
+            types::proposal::VoiceCredits::Flat(credits)
+            | types::proposal::VoiceCredits::PerToken(credits),
+        ) => isqrt(*credits),
+        _ => 1,
+    }
+}
+
+/// Returns the voice credits a member can spend on a single proposal.
+fn voice_credit_budget(
+    env: &Env,
+    voice_credits: &types::proposal::VoiceCredits,
+    member: Address,
+) -> u32 {
+    match voice_credits {
+        types::proposal::VoiceCredits::Flat(credits) => *credits,
+        types::proposal::VoiceCredits::PerToken(credits) => {
+            let budget = dao_token::balance(env, member).saturating_mul(*credits as i128);
+
+            budget.clamp(0, u32::MAX as i128) as u32
+        }
+    }
+}
+
+/// Integer square root, rounded down.
+fn isqrt(value: u32) -> u32 {
+    let value = value as u64;
+
+    if value < 2 {
+        return value as u32;
+    }
+
+    let mut root = value;
+    let mut next = (root + value / root) / 2;
+
+    while next < root {
+        root = next;
+        next = (root + value / root) / 2;
+    }
+
+    root as u32
 }
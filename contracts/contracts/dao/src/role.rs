@@ -0,0 +1,264 @@
+/// Module for member roles and the permissions they grant.
+use soroban_sdk::{Address, Env, Map, Symbol, Vec};
+
+use crate::ttl;
+use crate::types;
+use crate::types::data_key;
+
+/// Grants a role to a member.
+///
+/// # Arguments
+///
+/// - `member` - The member to grant the role to.
+/// - `role` - The role to grant.
+pub fn grant_role(env: &Env, member: Address, role: Symbol) {
+    let mut roles = read_roles(env, member.clone());
+
+    if roles.contains(&role) {
+        panic!("Member already has role");
+    }
+
+    let old_roles = roles.clone();
+
+    roles.push_back(role);
+
+    move_voter(env, member.clone(), &old_roles, &roles);
+
+    ttl::set(env, &data_key::Dao::Roles(member), &roles);
+}
+
+/// Revokes a role from a member.
+///
+/// # Arguments
+///
+/// - `member` - The member to revoke the role from.
+/// - `role` - The role to revoke.
+pub fn revoke_role(env: &Env, member: Address, role: Symbol) {
+    let mut roles = read_roles(env, member.clone());
+
+    let old_roles = roles.clone();
+
+    if let Some(index) = roles.first_index_of(&role) {
+        roles.remove(index);
+    } else {
+        panic!("Member does not have role");
+    }
+
+    move_voter(env, member.clone(), &old_roles, &roles);
+
+    ttl::set(env, &data_key::Dao::Roles(member), &roles);
+}
+
+/// Removes all roles of a member. The member must not have voting power anymore.
+///
+/// # Arguments
+///
+/// - `member` - The member whose roles are removed.
+pub fn clear_roles(env: &Env, member: Address) {
+    env.storage()
+        .persistent()
+        .remove(&data_key::Dao::Roles(member));
+}
+
+/// Reads the roles of a member.
+///
+/// # Arguments
+///
+/// - `member` - The member.
+pub fn read_roles(env: &Env, member: Address) -> Vec<Symbol> {
+    ttl::get(env, &data_key::Dao::Roles(member)).unwrap_or(Vec::new(env))
+}
+
+/// Checks if a member has a role.
+///
+/// # Arguments
+///
+/// - `member` - The member.
+/// - `role` - The role.
+pub fn has_role(env: &Env, member: Address, role: Symbol) -> bool {
+    read_roles(env, member).contains(&role)
+}
+
+/// Writes the roles allowed to perform an action on a type of proposal.
+///
+/// # Arguments
+///
+/// - `permission` - The permission to write.
+pub fn write_permission(env: &Env, permission: types::role::Permission) {
+    let key = data_key::Dao::Permission(permission.proposal_type, permission.action);
+
+    let mut permissions = read_permissions(env);
+    let entry = (permission.proposal_type, permission.action);
+
+    if permission.roles.is_empty() {
+        env.storage().persistent().remove(&key);
+
+        if let Some(index) = permissions.first_index_of(entry) {
+            permissions.remove(index);
+        }
+    } else {
+        ttl::set(env, &key, &permission.roles);
+
+        if !permissions.contains(entry) {
+            permissions.push_back(entry);
+        }
+    }
+
+    ttl::set(env, &data_key::Dao::Permissions, &permissions);
+}
+
+/// Reads the proposal types and actions which have a permission set.
+pub fn read_permissions(env: &Env) -> Vec<(types::proposal::Type, types::role::Action)> {
+    ttl::get(env, &data_key::Dao::Permissions).unwrap_or(Vec::new(env))
+}
+
+/// Reads the roles allowed to perform an action on a type of proposal.
+/// No roles means every member is allowed.
+///
+/// # Arguments
+///
+/// - `proposal_type` - The type of proposal.
+/// - `action` - The action.
+pub fn read_permission(
+    env: &Env,
+    proposal_type: types::proposal::Type,
+    action: types::role::Action,
+) -> Vec<Symbol> {
+    ttl::get(env, &data_key::Dao::Permission(proposal_type, action)).unwrap_or(Vec::new(env))
+}
+
+/// Checks if a member may perform an action on a type of proposal.
+///
+/// # Arguments
+///
+/// - `member` - The member.
+/// - `proposal_type` - The type of proposal.
+/// - `action` - The action.
+pub fn is_permitted(
+    env: &Env,
+    member: Address,
+    proposal_type: types::proposal::Type,
+    action: types::role::Action,
+) -> bool {
+    let allowed_roles = read_permission(env, proposal_type, action);
+
+    if allowed_roles.is_empty() {
+        return true;
+    }
+
+    let roles = read_roles(env, member);
+
+    allowed_roles.iter().any(|role| roles.contains(&role))
+}
+
+/// Records when a member was granted voting power and counts them among the voters with their roles.
+///
+/// # Arguments
+///
+/// - `member` - The member.
+pub fn add_voter(env: &Env, member: Address) {
+    ttl::set(
+        env,
+        &data_key::Dao::VoterSince(member.clone()),
+        &env.ledger().timestamp(),
+    );
+
+    update_voters(env, &read_roles(env, member), 1);
+}
+
+/// Counts a legacy member who was granted voting power before voters were tracked
+/// among the voters with their roles, as a voter since the creation of the DAO.
+///
+/// # Arguments
+///
+/// - `member` - The member.
+pub fn migrate_voter(env: &Env, member: Address) {
+    ttl::set(env, &data_key::Dao::VoterSince(member.clone()), &0_u64);
+
+    update_voters(env, &read_roles(env, member), 1);
+}
+
+/// Forgets when a member whose voting power was revoked was granted it and stops counting them
+/// among the voters with their roles.
+///
+/// # Arguments
+///
+/// - `member` - The member.
+pub fn remove_voter(env: &Env, member: Address) {
+    env.storage()
+        .persistent()
+        .remove(&data_key::Dao::VoterSince(member.clone()));
+
+    update_voters(env, &read_roles(env, member), -1);
+}
+
+/// Returns the time a member was granted their current voting power.
+///
+/// # Arguments
+///
+/// - `member` - The member.
+pub fn read_voter_since(env: &Env, member: Address) -> Option<u64> {
+    ttl::get(env, &data_key::Dao::VoterSince(member))
+}
+
+/// Returns the number of members with voting power who may vote on a type of proposal.
+/// Voters are counted per set of roles, so this does not have to load every member.
+///
+/// # Arguments
+///
+/// - `proposal_type` - The type of proposal.
+pub fn count_voters(env: &Env, proposal_type: types::proposal::Type) -> u32 {
+    let allowed_roles = read_permission(env, proposal_type, types::role::Action::Vote);
+
+    let mut count = 0;
+
+    for (roles, voters) in read_voters(env) {
+        if allowed_roles.is_empty() || allowed_roles.iter().any(|role| roles.contains(&role)) {
+            count += voters;
+        }
+    }
+
+    count
+}
+
+/// Moves a member from the voters with their old roles to the voters with their new roles
+/// if they have voting power.
+fn move_voter(env: &Env, member: Address, old_roles: &Vec<Symbol>, roles: &Vec<Symbol>) {
+    if crate::has_voting_power(env, member) {
+        update_voters(env, old_roles, -1);
+        update_voters(env, roles, 1);
+    }
+}
+
+fn update_voters(env: &Env, roles: &Vec<Symbol>, change: i32) {
+    let mut voters = read_voters(env);
+    let roles = role_set(env, roles);
+
+    let count = voters
+        .get(roles.clone())
+        .unwrap_or(0)
+        .saturating_add_signed(change);
+
+    if count == 0 {
+        voters.remove(roles);
+    } else {
+        voters.set(roles, count);
+    }
+
+    ttl::set(env, &data_key::Dao::VoterRoles, &voters);
+}
+
+fn read_voters(env: &Env) -> Map<Vec<Symbol>, u32> {
+    ttl::get(env, &data_key::Dao::VoterRoles).unwrap_or(Map::new(env))
+}
+
+/// Returns the roles sorted, so members with the same roles share the same set.
+fn role_set(env: &Env, roles: &Vec<Symbol>) -> Vec<Symbol> {
+    let mut set = Map::new(env);
+
+    for role in roles.iter() {
+        set.set(role, ());
+    }
+
+    set.keys()
+}
@@ -5,8 +5,9 @@ extern crate std;
 use std::println;
 
 use super::*;
-use soroban_sdk::testutils::{Address as AddressTest, Ledger, Logs};
-use soroban_sdk::{vec, Address, Env, IntoVal, String, Symbol, Val};
+use soroban_sdk::testutils::{Address as AddressTest, Events, Ledger, Logs};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{vec, Address, Env, IntoVal, String, Symbol, TryIntoVal, Val, Vec};
 
 use mock::*;
 
@@ -17,6 +18,16 @@ mod dao_token_contract {
 }
 
 fn create_dao<'a>(env: Env, members: &Vec<Address>) -> DaoContractClient<'a> {
+    let metadata = mock_dao_metadata(&env);
+
+    create_dao_with_metadata(env, members, metadata)
+}
+
+fn create_dao_with_metadata<'a>(
+    env: Env,
+    members: &Vec<Address>,
+    metadata: dao::Metadata,
+) -> DaoContractClient<'a> {
     let contract_id = env.register_contract(None, DaoContract);
     let dao = DaoContractClient::new(&env, &contract_id);
 
@@ -24,15 +35,7 @@ fn create_dao<'a>(env: Env, members: &Vec<Address>) -> DaoContractClient<'a> {
         .deployer()
         .upload_contract_wasm(dao_token_contract::WASM);
 
-    dao.initialize(
-        members,
-        &wasm_hash,
-        &dao::Metadata {
-            min_proposal_duration: 3600_u64,   // 1day
-            max_proposal_duration: 604800_u64, // 7 days
-            min_quorum_percentage: 50_u32,     // 50%
-        },
-    );
+    dao.initialize(members, &wasm_hash, &metadata);
 
     dao
 }
@@ -55,12 +58,26 @@ fn initialization() {
 
     let dao = create_dao(env.clone(), &initial_members);
 
-    let members = dao.get_members();
+    let members = dao.get_members(&0, &10);
 
     assert_eq!(members, initial_members);
     // env.logs().print();
 }
 
+#[test]
+#[should_panic(expected = "Member already exists")]
+fn initialization_duplicate_members() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let member = Address::generate(&env);
+
+    create_dao(
+        env.clone(),
+        &vec![&env, member.clone(), member, Address::generate(&env)],
+    );
+}
+
 #[test]
 fn has_voting_power() {
     let env = Env::default();
@@ -404,13 +421,13 @@ fn proposal_votes_get() {
 
     dao.vote_proposal(&initial_members.get(0).unwrap(), &proposal_id);
 
-    let votes = dao.get_proposal_votes(&proposal_id);
+    let votes = dao.get_proposal_votes(&proposal_id, &0, &10);
 
     assert_eq!(votes, vec![&env, initial_members.get(0).unwrap()]);
 
     dao.vote_proposal(&initial_members.get(2).unwrap(), &proposal_id);
 
-    let votes = dao.get_proposal_votes(&proposal_id);
+    let votes = dao.get_proposal_votes(&proposal_id, &0, &10);
 
     assert_eq!(
         votes,
@@ -435,7 +452,7 @@ fn proposal_votes_get() {
         li.timestamp = 5500;
     });
 
-    let votes = dao.get_proposal_votes(&proposal_id);
+    let votes = dao.get_proposal_votes(&proposal_id, &0, &10);
 
     assert_eq!(votes, Vec::new(&env));
 
@@ -445,13 +462,13 @@ fn proposal_votes_get() {
 
     dao.vote_proposal(&initial_members.get(0).unwrap(), &proposal_id);
 
-    let votes = dao.get_proposal_votes(&proposal_id);
+    let votes = dao.get_proposal_votes(&proposal_id, &0, &10);
 
     assert_eq!(votes, vec![&env, initial_members.get(0).unwrap()]);
 
     dao.vote_proposal(&initial_members.get(1).unwrap(), &proposal_id);
 
-    let votes = dao.get_proposal_votes(&proposal_id);
+    let votes = dao.get_proposal_votes(&proposal_id, &0, &10);
 
     assert_eq!(
         votes,
@@ -496,7 +513,7 @@ fn add_member_proposal_evaluate() {
     dao.vote_proposal(&member_a, &proposal_id);
     dao.vote_proposal(&member_b, &proposal_id);
 
-    let members = dao.get_members();
+    let members = dao.get_members(&0, &10);
     assert_eq!(members, initial_members);
 
     env.ledger().with_mut(|li| {
@@ -504,14 +521,23 @@ fn add_member_proposal_evaluate() {
     });
 
     dao.evaluate_add_member_proposal(&member_a, &proposal_id);
+    dao.consent_proposal(&a, &proposal_id);
 
-    let members = dao.get_members();
+    let members = dao.get_members(&0, &10);
 
     let mut expected_members = initial_members.clone();
-    expected_members.append(&vec![&env, a]);
+    expected_members.append(&vec![&env, a.clone()]);
 
     assert_eq!(members, expected_members);
 
+    let record = dao.get_member(&a);
+
+    assert_eq!(record.joined_at, 27000);
+    assert_eq!(
+        record.admission,
+        types::member::Admission::Proposal(proposal_id)
+    );
+
     // env.logs().print();
 }
 
@@ -568,7 +594,7 @@ fn remove_member_proposal_evaluate() {
     dao.vote_proposal(&member_a, &proposal_id);
     dao.vote_proposal(&member_b, &proposal_id);
 
-    let members = dao.get_members();
+    let members = dao.get_members(&0, &10);
     assert_eq!(members, initial_members);
 
     env.ledger().with_mut(|li| {
@@ -577,13 +603,14 @@ fn remove_member_proposal_evaluate() {
 
     dao.evaluate_remove_member_proposal(&member_a, &proposal_id);
 
-    let members = dao.get_members();
+    let members = dao.get_members(&0, &10);
 
     let mut expected_members = initial_members.clone();
     let index = expected_members.first_index_of(&member_b).unwrap();
     expected_members.remove(index);
 
     assert_eq!(members, expected_members);
+    assert!(!dao.has_voting_power(&member_b));
 
     // env.logs().print();
 }
@@ -626,6 +653,7 @@ fn grant_voting_proposal() {
     });
 
     dao.evaluate_add_member_proposal(&member_a, &proposal_id);
+    dao.consent_proposal(&a, &proposal_id);
 
     env.ledger().with_mut(|li| {
         li.timestamp = 11000;
@@ -726,6 +754,7 @@ fn grant_voting_proposal_evaluate() {
     });
 
     dao.evaluate_add_member_proposal(&member_a, &proposal_id);
+    dao.consent_proposal(&a, &proposal_id);
 
     env.ledger().with_mut(|li| {
         li.timestamp = 5200;
@@ -758,6 +787,7 @@ fn grant_voting_proposal_evaluate() {
     });
 
     dao.evaluate_grant_voting_proposal(&member_a, &proposal_id);
+    dao.consent_proposal(&a, &proposal_id);
 
     let has_voting_power = dao.has_voting_power(&a);
 
@@ -805,6 +835,7 @@ fn revoke_voting_proposal() {
     });
 
     dao.evaluate_add_member_proposal(&member_a, &proposal_id);
+    dao.consent_proposal(&a, &proposal_id);
 
     env.ledger().with_mut(|li| {
         li.timestamp = 5200;
@@ -833,6 +864,7 @@ fn revoke_voting_proposal() {
     });
 
     dao.evaluate_grant_voting_proposal(&member_a, &proposal_id);
+    dao.consent_proposal(&a, &proposal_id);
 
     env.ledger().with_mut(|li| {
         li.timestamp = 5400;
@@ -888,6 +920,7 @@ fn revoke_voting_proposal_has_power() {
     });
 
     dao.evaluate_add_member_proposal(&member_a, &proposal_id);
+    dao.consent_proposal(&a, &proposal_id);
 
     env.ledger().with_mut(|li| {
         li.timestamp = 5200;
@@ -943,6 +976,7 @@ fn revoke_voting_proposal_evaluate() {
     });
 
     dao.evaluate_add_member_proposal(&member_a, &proposal_id);
+    dao.consent_proposal(&a, &proposal_id);
 
     env.ledger().with_mut(|li| {
         li.timestamp = 5200;
@@ -971,11 +1005,15 @@ fn revoke_voting_proposal_evaluate() {
     });
 
     dao.evaluate_grant_voting_proposal(&member_a, &proposal_id);
+    dao.consent_proposal(&a, &proposal_id);
 
     env.ledger().with_mut(|li| {
         li.timestamp = 5400;
     });
 
+    // The revocation runs in later transactions with a budget of their own.
+    env.budget().reset_default();
+
     let proposal_id = mock_proposal(
         &env,
         &dao,
@@ -1087,6 +1125,54 @@ fn general_purpose_proposal_evaluate() {
     assert_eq!(status, types::proposal::Status::Accepted);
 }
 
+#[test]
+fn proposal_status_live() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member = initial_members.get(0).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let proposal_id = mock_proposal(
+        &env,
+        &dao,
+        &member,
+        &member,
+        None,
+        None,
+        types::proposal::Type::GeneralPurpose,
+    );
+
+    assert_eq!(
+        dao.get_proposal_status(&proposal_id),
+        types::proposal::Status::NotStarted
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    assert_eq!(
+        dao.get_proposal_status(&proposal_id),
+        types::proposal::Status::Active
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    assert_eq!(
+        dao.get_proposal_status(&proposal_id),
+        types::proposal::Status::Ended
+    );
+}
+
 #[test]
 fn read_proposals() {
     let env = Env::default();
@@ -1122,3 +1208,3439 @@ fn read_proposals() {
         }
     );
 }
+
+#[test]
+fn quadratic_vote_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let mut metadata = mock_proposal_metadata(&env, &member_a, None, None);
+    metadata.voting_mode =
+        types::proposal::VotingMode::Quadratic(types::proposal::VoiceCredits::Flat(100));
+
+    let rejected_id = dao.general_purpose_proposal(&types::proposal::GeneralPurpose {
+        metadata: metadata.clone(),
+        actions: String::from_str(&env, "mock actions"),
+    });
+
+    metadata.name = String::from_str(&env, "accepted");
+
+    let accepted_id = dao.general_purpose_proposal(&types::proposal::GeneralPurpose {
+        metadata,
+        actions: String::from_str(&env, "mock actions"),
+    });
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    // 10 + 5 effective votes out of 30 possible is not more than the 50% quorum
+    dao.quadratic_vote_proposal(&member_a, &rejected_id, &100);
+    dao.quadratic_vote_proposal(&member_b, &rejected_id, &25);
+
+    dao.quadratic_vote_proposal(&member_a, &accepted_id, &100);
+    dao.quadratic_vote_proposal(&member_b, &accepted_id, &64);
+
+    assert_eq!(dao.get_proposal_tally(&rejected_id), 15);
+    assert_eq!(dao.get_proposal_tally(&accepted_id), 18);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.evaluate_general_proposal(&member_a, &rejected_id);
+    dao.evaluate_general_proposal(&member_a, &accepted_id);
+
+    assert_eq!(
+        dao.get_proposal_status(&rejected_id),
+        types::proposal::Status::Rejected
+    );
+    assert_eq!(
+        dao.get_proposal_status(&accepted_id),
+        types::proposal::Status::Accepted
+    );
+}
+
+#[test]
+#[should_panic(expected = "Not enough voice credits")]
+fn quadratic_vote_proposal_over_budget() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let mut metadata = mock_proposal_metadata(&env, &member_a, None, None);
+    metadata.voting_mode =
+        types::proposal::VotingMode::Quadratic(types::proposal::VoiceCredits::PerToken(9));
+
+    let proposal_id = dao.general_purpose_proposal(&types::proposal::GeneralPurpose {
+        metadata,
+        actions: String::from_str(&env, "mock actions"),
+    });
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.quadratic_vote_proposal(&member_a, &proposal_id, &10);
+}
+
+#[test]
+#[should_panic(expected = "Proposal uses quadratic voting")]
+fn quadratic_vote_proposal_simple_vote() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let mut metadata = mock_proposal_metadata(&env, &member_a, None, None);
+    metadata.voting_mode =
+        types::proposal::VotingMode::Quadratic(types::proposal::VoiceCredits::Flat(100));
+
+    let proposal_id = dao.general_purpose_proposal(&types::proposal::GeneralPurpose {
+        metadata,
+        actions: String::from_str(&env, "mock actions"),
+    });
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.vote_proposal(&member_a, &proposal_id);
+}
+
+#[test]
+fn secret_ballot_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+    let member_c = initial_members.get(2).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let mut metadata = mock_proposal_metadata(&env, &member_a, None, None);
+    metadata.ballot = types::proposal::Ballot::Secret(3600);
+
+    let proposal_id = dao.general_purpose_proposal(&types::proposal::GeneralPurpose {
+        metadata,
+        actions: String::from_str(&env, "mock actions"),
+    });
+
+    let salt = BytesN::from_array(&env, &[7_u8; 32]);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.commit_vote_proposal(
+        &member_a,
+        &proposal_id,
+        &proposal::commitment_hash(&env, true, salt.clone()),
+    );
+    dao.commit_vote_proposal(
+        &member_b,
+        &proposal_id,
+        &proposal::commitment_hash(&env, false, salt.clone()),
+    );
+    dao.commit_vote_proposal(
+        &member_c,
+        &proposal_id,
+        &proposal::commitment_hash(&env, true, salt.clone()),
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.reveal_vote_proposal(&member_a, &proposal_id, &true, &salt);
+    dao.reveal_vote_proposal(&member_b, &proposal_id, &false, &salt);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 30000;
+    });
+
+    // the commitment of member c was never revealed and is ignored
+    assert_eq!(
+        dao.get_proposal_votes(&proposal_id, &0, &10),
+        vec![&env, member_a.clone()]
+    );
+
+    dao.evaluate_general_proposal(&member_a, &proposal_id);
+
+    assert_eq!(
+        dao.get_proposal_status(&proposal_id),
+        types::proposal::Status::Rejected
+    );
+}
+
+#[test]
+#[should_panic(expected = "Votes are sealed until the reveal phase ends")]
+fn secret_ballot_proposal_sealed_votes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let mut metadata = mock_proposal_metadata(&env, &member_a, None, None);
+    metadata.ballot = types::proposal::Ballot::Secret(3600);
+
+    let proposal_id = dao.general_purpose_proposal(&types::proposal::GeneralPurpose {
+        metadata,
+        actions: String::from_str(&env, "mock actions"),
+    });
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.get_proposal_votes(&proposal_id, &0, &10);
+}
+
+#[test]
+#[should_panic(expected = "Commitment does not match")]
+fn secret_ballot_proposal_wrong_reveal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let mut metadata = mock_proposal_metadata(&env, &member_a, None, None);
+    metadata.ballot = types::proposal::Ballot::Secret(3600);
+
+    let proposal_id = dao.general_purpose_proposal(&types::proposal::GeneralPurpose {
+        metadata,
+        actions: String::from_str(&env, "mock actions"),
+    });
+
+    let salt = BytesN::from_array(&env, &[7_u8; 32]);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.commit_vote_proposal(
+        &member_a,
+        &proposal_id,
+        &proposal::commitment_hash(&env, false, salt.clone()),
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.reveal_vote_proposal(&member_a, &proposal_id, &true, &salt);
+}
+
+#[test]
+#[should_panic(expected = "Only members can vote")]
+fn secret_ballot_proposal_reveal_after_leaving() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let mut initial_members = get_initial_members(&env);
+    initial_members.push_back(Address::generate(&env));
+    let member_a = initial_members.get(0).unwrap();
+    let member_d = initial_members.get(3).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let mut metadata = mock_proposal_metadata(&env, &member_a, None, None);
+    metadata.ballot = types::proposal::Ballot::Secret(3600);
+
+    let proposal_id = dao.general_purpose_proposal(&types::proposal::GeneralPurpose {
+        metadata,
+        actions: String::from_str(&env, "mock actions"),
+    });
+
+    let salt = BytesN::from_array(&env, &[7_u8; 32]);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.commit_vote_proposal(
+        &member_d,
+        &proposal_id,
+        &proposal::commitment_hash(&env, true, salt.clone()),
+    );
+
+    dao.leave(&member_d);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.reveal_vote_proposal(&member_d, &proposal_id, &true, &salt);
+}
+
+#[test]
+fn early_resolution_proposal_evaluate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+    let a = Address::generate(&env);
+
+    let mut metadata = mock_dao_metadata(&env);
+    metadata.early_resolution = true;
+
+    let dao = create_dao_with_metadata(env.clone(), &initial_members, metadata);
+
+    let proposal_id = mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &a,
+        None,
+        None,
+        types::proposal::Type::AddMember,
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.vote_proposal(&member_a, &proposal_id);
+    dao.vote_proposal(&member_b, &proposal_id);
+
+    dao.evaluate_add_member_proposal(&member_a, &proposal_id);
+    dao.consent_proposal(&a, &proposal_id);
+
+    assert_eq!(
+        dao.get_proposal_status(&proposal_id),
+        types::proposal::Status::Accepted
+    );
+    assert!(dao.get_members(&0, &10).contains(&a));
+}
+
+#[test]
+#[should_panic(expected = "Proposal outcome is not decided yet")]
+fn early_resolution_proposal_undecided() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let a = Address::generate(&env);
+
+    let mut metadata = mock_dao_metadata(&env);
+    metadata.early_resolution = true;
+
+    let dao = create_dao_with_metadata(env.clone(), &initial_members, metadata);
+
+    let proposal_id = mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &a,
+        None,
+        None,
+        types::proposal::Type::AddMember,
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.vote_proposal(&member_a, &proposal_id);
+
+    dao.evaluate_add_member_proposal(&member_a, &proposal_id);
+}
+
+#[test]
+fn optimistic_proposal_evaluate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+    let member_c = initial_members.get(2).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let mut metadata = mock_proposal_metadata(&env, &member_a, None, None);
+    metadata.ballot = types::proposal::Ballot::Optimistic;
+
+    let accepted_id = dao.general_purpose_proposal(&types::proposal::GeneralPurpose {
+        metadata: metadata.clone(),
+        actions: String::from_str(&env, "mock actions"),
+    });
+
+    metadata.name = String::from_str(&env, "vetoed");
+
+    let vetoed_id = dao.general_purpose_proposal(&types::proposal::GeneralPurpose {
+        metadata,
+        actions: String::from_str(&env, "mock actions"),
+    });
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.object_proposal(&member_c, &accepted_id);
+
+    dao.object_proposal(&member_b, &vetoed_id);
+    dao.object_proposal(&member_c, &vetoed_id);
+
+    assert_eq!(
+        dao.get_proposal_objections(&vetoed_id, &0, &10),
+        vec![&env, member_b.clone(), member_c.clone()]
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.evaluate_general_proposal(&member_a, &accepted_id);
+    dao.evaluate_general_proposal(&member_a, &vetoed_id);
+
+    assert_eq!(
+        dao.get_proposal_status(&accepted_id),
+        types::proposal::Status::Accepted
+    );
+    assert_eq!(
+        dao.get_proposal_status(&vetoed_id),
+        types::proposal::Status::Rejected
+    );
+}
+
+#[test]
+#[should_panic(expected = "Optimistic proposals take objections, not votes")]
+fn optimistic_proposal_vote() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let mut metadata = mock_proposal_metadata(&env, &member_a, None, None);
+    metadata.ballot = types::proposal::Ballot::Optimistic;
+
+    let proposal_id = dao.general_purpose_proposal(&types::proposal::GeneralPurpose {
+        metadata,
+        actions: String::from_str(&env, "mock actions"),
+    });
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.vote_proposal(&member_a, &proposal_id);
+}
+
+#[test]
+fn guardian_veto_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+    let guardian = Address::generate(&env);
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let proposal_id = mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &guardian,
+        None,
+        None,
+        types::proposal::Type::SetGuardians,
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.vote_proposal(&member_a, &proposal_id);
+    dao.vote_proposal(&member_b, &proposal_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.evaluate_set_guardians_proposal(&member_a, &proposal_id);
+
+    assert_eq!(dao.get_guardians(), vec![&env, guardian.clone()]);
+
+    let proposal_id = mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &member_b,
+        Some(27100),
+        Some(40000),
+        types::proposal::Type::RemoveMember,
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27200;
+    });
+
+    dao.vote_proposal(&member_a, &proposal_id);
+    dao.vote_proposal(&member_b, &proposal_id);
+
+    dao.veto_proposal(&guardian, &proposal_id);
+
+    assert_eq!(
+        dao.get_proposal_status(&proposal_id),
+        types::proposal::Status::Vetoed
+    );
+}
+
+#[test]
+#[should_panic(expected = "Only guardians can veto proposals")]
+fn guardian_veto_proposal_not_guardian() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let proposal_id = mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &member_a,
+        None,
+        None,
+        types::proposal::Type::GeneralPurpose,
+    );
+
+    dao.veto_proposal(&member_a, &proposal_id);
+}
+
+#[test]
+#[should_panic(expected = "Guardians cannot veto proposals to set the guardians")]
+fn guardian_veto_set_guardians_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+    let guardian = Address::generate(&env);
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let proposal_id = mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &guardian,
+        None,
+        None,
+        types::proposal::Type::SetGuardians,
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.vote_proposal(&member_a, &proposal_id);
+    dao.vote_proposal(&member_b, &proposal_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.evaluate_set_guardians_proposal(&member_a, &proposal_id);
+
+    let proposal_id = mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &member_b,
+        Some(27100),
+        Some(40000),
+        types::proposal::Type::SetGuardians,
+    );
+
+    dao.veto_proposal(&guardian, &proposal_id);
+}
+
+#[test]
+fn emergency_pause() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    dao.pause(&member_a);
+
+    assert!(!dao.is_paused());
+
+    dao.pause(&member_b);
+
+    assert!(dao.is_paused());
+    assert_eq!(dao.get_paused_until(), 5000 + 86400);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000 + 86400;
+    });
+
+    // the pause has expired
+    assert!(!dao.is_paused());
+
+    mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &member_a,
+        Some(5000 + 86500),
+        Some(5000 + 96500),
+        types::proposal::Type::GeneralPurpose,
+    );
+}
+
+#[test]
+#[should_panic(expected = "DAO is paused")]
+fn emergency_pause_vote() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let proposal_id = mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &member_a,
+        None,
+        None,
+        types::proposal::Type::GeneralPurpose,
+    );
+
+    dao.pause(&member_a);
+    dao.pause(&member_b);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.vote_proposal(&member_a, &proposal_id);
+}
+
+#[test]
+fn emergency_pause_vote_expired() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    dao.pause(&member_a);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000 + mock_dao_metadata(&env).max_pause_duration;
+    });
+
+    dao.pause(&member_b);
+
+    assert!(!dao.is_paused());
+}
+
+#[test]
+fn emergency_pause_vote_left_member() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let mut initial_members = get_initial_members(&env);
+    initial_members.push_back(Address::generate(&env));
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    dao.pause(&member_a);
+    dao.leave(&member_a);
+
+    // the vote of member a was dropped when they left
+    dao.pause(&member_b);
+
+    assert!(!dao.is_paused());
+}
+
+#[test]
+#[should_panic(expected = "Pause cooldown has not passed yet")]
+fn emergency_pause_cooldown() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+    let guardian = Address::generate(&env);
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let proposal_id = mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &guardian,
+        None,
+        None,
+        types::proposal::Type::SetGuardians,
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.vote_proposal(&member_a, &proposal_id);
+    dao.vote_proposal(&member_b, &proposal_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.evaluate_set_guardians_proposal(&member_a, &proposal_id);
+
+    dao.pause(&guardian);
+    dao.unpause(&guardian);
+
+    assert!(!dao.is_paused());
+
+    dao.pause(&guardian);
+}
+
+fn restrict_general_purpose_to_council(env: &Env, dao: &DaoContractClient, council: &Address) {
+    let members = dao.get_members(&0, &10);
+    let member_a = members.get(0).unwrap();
+    let member_b = members.get(1).unwrap();
+
+    let proposal_id = mock_proposal(
+        env,
+        dao,
+        &member_a,
+        council,
+        None,
+        None,
+        types::proposal::Type::GrantRole,
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.vote_proposal(&member_a, &proposal_id);
+    dao.vote_proposal(&member_b, &proposal_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.evaluate_grant_role_proposal(&member_a, &proposal_id);
+
+    let proposal_id = mock_proposal(
+        env,
+        dao,
+        &member_a,
+        &member_a,
+        Some(27100),
+        Some(40000),
+        types::proposal::Type::SetPermission,
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27200;
+    });
+
+    dao.vote_proposal(&member_a, &proposal_id);
+    dao.vote_proposal(&member_b, &proposal_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 45000;
+    });
+
+    dao.evaluate_set_permission_proposal(&member_a, &proposal_id);
+}
+
+#[test]
+fn role_vote_permission_quorum() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+    let member_c = initial_members.get(2).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let grant_role_id = mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &member_c,
+        None,
+        None,
+        types::proposal::Type::GrantRole,
+    );
+    let set_permission_id = dao.set_permission_proposal(&types::proposal::SetPermission {
+        metadata: mock_proposal_metadata(&env, &member_a, None, None),
+        permission: types::role::Permission {
+            proposal_type: types::proposal::Type::GeneralPurpose,
+            action: types::role::Action::Vote,
+            roles: vec![&env, Symbol::new(&env, "council")],
+        },
+    });
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    for id in [grant_role_id.clone(), set_permission_id.clone()] {
+        dao.vote_proposal(&member_a, &id);
+        dao.vote_proposal(&member_b, &id);
+    }
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.evaluate_grant_role_proposal(&member_a, &grant_role_id);
+    dao.evaluate_set_permission_proposal(&member_a, &set_permission_id);
+
+    let proposal_id = mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &member_a,
+        Some(27100),
+        Some(40000),
+        types::proposal::Type::GeneralPurpose,
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27200;
+    });
+
+    // the council is the only member permitted to vote, so its vote reaches the quorum
+    dao.vote_proposal(&member_c, &proposal_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 45000;
+    });
+
+    dao.evaluate_general_proposal(&member_a, &proposal_id);
+
+    assert_eq!(
+        dao.get_proposal_status(&proposal_id),
+        types::proposal::Status::Accepted
+    );
+}
+
+#[test]
+fn role_permission_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_c = initial_members.get(2).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    restrict_general_purpose_to_council(&env, &dao, &member_c);
+
+    let council = Symbol::new(&env, "council");
+
+    assert_eq!(dao.get_member_roles(&member_c), vec![&env, council.clone()]);
+    assert_eq!(
+        dao.get_permission(
+            &types::proposal::Type::GeneralPurpose,
+            &types::role::Action::Propose
+        ),
+        vec![&env, council]
+    );
+
+    mock_proposal(
+        &env,
+        &dao,
+        &member_c,
+        &member_c,
+        Some(45100),
+        Some(50000),
+        types::proposal::Type::GeneralPurpose,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Proposer does not have a role allowed to create this proposal")]
+fn role_permission_proposal_missing_role() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_c = initial_members.get(2).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    restrict_general_purpose_to_council(&env, &dao, &member_c);
+
+    mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &member_a,
+        Some(45100),
+        Some(50000),
+        types::proposal::Type::GeneralPurpose,
+    );
+}
+
+#[test]
+fn members_pagination() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+    let member_c = initial_members.get(2).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    assert_eq!(dao.get_member_count(), 3);
+    assert_eq!(
+        dao.get_members(&0, &2),
+        vec![&env, member_a.clone(), member_b]
+    );
+    assert_eq!(dao.get_members(&2, &2), vec![&env, member_c]);
+    assert_eq!(dao.get_members(&3, &2), vec![&env]);
+
+    env.as_contract(&dao.address, || {
+        member::remove_member(&env, member_a);
+    });
+
+    assert_eq!(dao.get_member_count(), 2);
+    assert_eq!(
+        dao.get_members(&0, &10),
+        vec![
+            &env,
+            initial_members.get(2).unwrap(),
+            initial_members.get(1).unwrap()
+        ]
+    );
+}
+
+#[test]
+fn members_migration() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_members = get_initial_members(&env);
+    let legacy_a = Address::generate(&env);
+    let legacy_b = Address::generate(&env);
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    env.as_contract(&dao.address, || {
+        env.storage().persistent().set(
+            &types::data_key::Dao::Members,
+            &vec![&env, legacy_a.clone(), legacy_b.clone()],
+        );
+    });
+
+    assert_eq!(dao.get_member_count(), 5);
+    assert_eq!(
+        dao.get_members(&3, &2),
+        vec![&env, legacy_a.clone(), legacy_b.clone()]
+    );
+
+    assert_eq!(dao.migrate_members(&1), 1);
+    assert_eq!(dao.migrate_members(&1), 0);
+
+    assert_eq!(dao.get_member_count(), 5);
+    assert_eq!(
+        dao.get_members(&3, &2),
+        vec![&env, legacy_a.clone(), legacy_b.clone()]
+    );
+
+    env.as_contract(&dao.address, || {
+        assert!(member::is_member(&env, legacy_b));
+        assert!(!env
+            .storage()
+            .persistent()
+            .has(&types::data_key::Dao::Members));
+    });
+}
+
+#[test]
+fn members_migration_vote() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let legacy_a = Address::generate(&env);
+    let legacy_b = Address::generate(&env);
+    let legacy_c = Address::generate(&env);
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let token = env.as_contract(&dao.address, || {
+        env.storage().persistent().set(
+            &types::data_key::Dao::Members,
+            &vec![&env, legacy_a.clone(), legacy_b.clone(), legacy_c.clone()],
+        );
+
+        env.storage()
+            .persistent()
+            .get::<_, Address>(&types::data_key::Dao::TokenContractId)
+            .unwrap()
+    });
+
+    // Legacy tokens were minted before the total supply was tracked.
+    let token = dao_token_contract::Client::new(&env, &token);
+
+    for legacy in [&legacy_a, &legacy_b, &legacy_c] {
+        token.mint(legacy, &1);
+    }
+
+    env.as_contract(&token.address, || {
+        env.storage()
+            .persistent()
+            .set(&(Symbol::new(&env, "TotalSupply"),), &3_i128);
+    });
+
+    assert_eq!(dao.migrate_members(&10), 0);
+    assert_eq!(token.total_supply(), 6);
+
+    let proposal_id = mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &member_a,
+        None,
+        None,
+        types::proposal::Type::GeneralPurpose,
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.vote_proposal(&member_a, &proposal_id);
+    dao.vote_proposal(&legacy_a, &proposal_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.evaluate_general_proposal(&member_a, &proposal_id);
+
+    assert_eq!(
+        dao.get_proposal_status(&proposal_id),
+        types::proposal::Status::Rejected
+    );
+}
+
+#[test]
+fn member_profile_update() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let record = dao.get_member(&member_a);
+
+    assert_eq!(record.joined_at, 5000);
+    assert_eq!(record.admission, types::member::Admission::Initial);
+    assert_eq!(record.name, String::from_str(&env, ""));
+
+    let name = String::from_str(&env, "alice");
+    let profile_hash = BytesN::from_array(&env, &[7; 32]);
+
+    dao.update_member_profile(&member_a, &name, &profile_hash);
+
+    let record = dao.get_member(&member_a);
+
+    assert_eq!(record.name, name);
+    assert_eq!(record.profile_hash, profile_hash);
+}
+
+#[test]
+fn member_leave() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let mut initial_members = get_initial_members(&env);
+    initial_members.push_back(Address::generate(&env));
+    let member_a = initial_members.get(0).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    assert!(dao.has_voting_power(&member_a));
+
+    dao.leave(&member_a);
+
+    assert!(!dao.get_members(&0, &10).contains(&member_a));
+    assert!(!dao.has_voting_power(&member_a));
+}
+
+#[test]
+#[should_panic(expected = "DAO must keep atleast min_members members")]
+fn member_leave_min_members() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+
+    let mut metadata = mock_dao_metadata(&env);
+    metadata.min_members = 3;
+
+    let dao = create_dao_with_metadata(env.clone(), &initial_members, metadata);
+
+    dao.leave(&member_a);
+}
+
+fn create_dao_with_treasury<'a>(
+    env: &Env,
+    members: &Vec<Address>,
+) -> (DaoContractClient<'a>, Address) {
+    let asset = env.register_stellar_asset_contract(Address::generate(env));
+
+    let mut metadata = mock_dao_metadata(env);
+    metadata.treasury_assets = vec![env, asset.clone()];
+
+    let dao = create_dao_with_metadata(env.clone(), members, metadata);
+
+    soroban_sdk::token::StellarAssetClient::new(env, &asset).mint(&dao.address, &1000);
+
+    (dao, asset)
+}
+
+fn accepted_spend_proposal(env: &Env, dao: &DaoContractClient, recipient: &Address) -> BytesN<32> {
+    let members = dao.get_members(&0, &10);
+    let member_a = members.get(0).unwrap();
+
+    let proposal_id = mock_proposal(
+        env,
+        dao,
+        &member_a,
+        recipient,
+        None,
+        None,
+        types::proposal::Type::Spend,
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    for member in members.slice(0..3) {
+        dao.vote_proposal(&member, &proposal_id);
+    }
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.evaluate_spend_proposal(&member_a, &proposal_id);
+
+    proposal_id
+}
+
+#[test]
+fn spend_proposal_ragequit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let mut initial_members = get_initial_members(&env);
+    initial_members.push_back(Address::generate(&env));
+    let member_d = initial_members.get(3).unwrap();
+    let recipient = Address::generate(&env);
+
+    let (dao, asset) = create_dao_with_treasury(&env, &initial_members);
+    let token = soroban_sdk::token::Client::new(&env, &asset);
+
+    let proposal_id = accepted_spend_proposal(&env, &dao, &recipient);
+
+    dao.ragequit(&member_d, &proposal_id);
+
+    assert_eq!(token.balance(&member_d), 250);
+    assert_eq!(token.balance(&dao.address), 750);
+    assert!(!dao.get_members(&0, &10).contains(&member_d));
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000 + 86401;
+    });
+
+    dao.execute_spend_proposal(&proposal_id);
+
+    assert_eq!(token.balance(&recipient), 100);
+    assert_eq!(token.balance(&dao.address), 650);
+}
+
+#[test]
+fn spend_proposal_ragequit_escrowed_bond() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let mut initial_members = get_initial_members(&env);
+    initial_members.push_back(Address::generate(&env));
+    let member_a = initial_members.get(0).unwrap();
+    let member_d = initial_members.get(3).unwrap();
+    let recipient = Address::generate(&env);
+
+    let asset = env.register_stellar_asset_contract(Address::generate(&env));
+    let token = soroban_sdk::token::Client::new(&env, &asset);
+
+    let mut metadata = mock_dao_metadata(&env);
+    metadata.treasury_assets = vec![&env, asset.clone()];
+    metadata.proposal_bond = dao::Bond::Asset(asset.clone(), 10);
+
+    let dao = create_dao_with_metadata(env.clone(), &initial_members, metadata);
+
+    soroban_sdk::token::StellarAssetClient::new(&env, &asset).mint(&dao.address, &1000);
+    soroban_sdk::token::StellarAssetClient::new(&env, &asset).mint(&member_a, &100);
+
+    // the bond of this proposal stays escrowed while it is open
+    mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &member_a,
+        None,
+        None,
+        types::proposal::Type::GeneralPurpose,
+    );
+
+    let proposal_id = accepted_spend_proposal(&env, &dao, &recipient);
+
+    assert_eq!(token.balance(&dao.address), 1010);
+
+    dao.ragequit(&member_d, &proposal_id);
+
+    assert_eq!(token.balance(&member_d), 250);
+    assert_eq!(token.balance(&dao.address), 760);
+}
+
+#[test]
+#[should_panic(expected = "Members who voted for the spend cannot ragequit")]
+fn spend_proposal_ragequit_voted() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let mut initial_members = get_initial_members(&env);
+    initial_members.push_back(Address::generate(&env));
+    let member_a = initial_members.get(0).unwrap();
+    let recipient = Address::generate(&env);
+
+    let (dao, _) = create_dao_with_treasury(&env, &initial_members);
+
+    let proposal_id = accepted_spend_proposal(&env, &dao, &recipient);
+
+    dao.ragequit(&member_a, &proposal_id);
+}
+
+#[test]
+#[should_panic(expected = "Member was granted voting power after the spend was accepted")]
+fn spend_proposal_ragequit_late_voter() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let mut initial_members = get_initial_members(&env);
+    initial_members.push_back(Address::generate(&env));
+    let member_a = initial_members.get(0).unwrap();
+    let recipient = Address::generate(&env);
+    let e = Address::generate(&env);
+
+    let (dao, _) = create_dao_with_treasury(&env, &initial_members);
+
+    let add_member_id = mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &e,
+        None,
+        None,
+        types::proposal::Type::AddMember,
+    );
+    let spend_id = mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &recipient,
+        None,
+        None,
+        types::proposal::Type::Spend,
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    for member in initial_members.slice(0..3) {
+        dao.vote_proposal(&member, &add_member_id);
+        dao.vote_proposal(&member, &spend_id);
+    }
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.evaluate_spend_proposal(&member_a, &spend_id);
+    dao.evaluate_add_member_proposal(&member_a, &add_member_id);
+    dao.consent_proposal(&e, &add_member_id);
+
+    let grant_id = mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &e,
+        Some(27100),
+        Some(40000),
+        types::proposal::Type::GrantVotingPower,
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27200;
+    });
+
+    for member in initial_members.slice(0..3) {
+        dao.vote_proposal(&member, &grant_id);
+    }
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 45000;
+    });
+
+    dao.evaluate_grant_voting_proposal(&member_a, &grant_id);
+    dao.consent_proposal(&e, &grant_id);
+
+    dao.ragequit(&e, &spend_id);
+}
+
+#[test]
+fn membership_application() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+    let applicant = Address::generate(&env);
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    dao.apply_for_membership(&applicant, &String::from_str(&env, "let me in"));
+
+    assert_eq!(dao.get_application(&applicant).expires_at, 5000 + 604800);
+
+    let proposal_id = dao.sponsor_application(
+        &applicant,
+        &mock_proposal_metadata(&env, &member_a, None, None),
+    );
+
+    assert_eq!(
+        dao.get_add_member_proposal(&proposal_id).candidate,
+        applicant
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.vote_proposal(&member_a, &proposal_id);
+    dao.vote_proposal(&member_b, &proposal_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.evaluate_add_member_proposal(&member_a, &proposal_id);
+    dao.consent_proposal(&applicant, &proposal_id);
+
+    assert!(dao.get_members(&0, &10).contains(&applicant));
+}
+
+#[test]
+#[should_panic(expected = "Application has expired")]
+fn membership_application_expired() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let applicant = Address::generate(&env);
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    dao.apply_for_membership(&applicant, &String::from_str(&env, "let me in"));
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000 + 604801;
+    });
+
+    dao.sponsor_application(
+        &applicant,
+        &mock_proposal_metadata(&env, &member_a, None, None),
+    );
+}
+
+#[test]
+fn add_member_proposal_lapsed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+    let a = Address::generate(&env);
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let proposal_id = mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &a,
+        None,
+        None,
+        types::proposal::Type::AddMember,
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.vote_proposal(&member_a, &proposal_id);
+    dao.vote_proposal(&member_b, &proposal_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.evaluate_add_member_proposal(&member_a, &proposal_id);
+
+    assert!(!dao.get_members(&0, &10).contains(&a));
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000 + 86401;
+    });
+
+    assert_eq!(
+        dao.get_proposal_status(&proposal_id),
+        types::proposal::Status::Lapsed
+    );
+}
+
+#[test]
+fn grant_voting_proposal_consent_after_leaving() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+    let a = Address::generate(&env);
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let add_id = mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &a,
+        None,
+        None,
+        types::proposal::Type::AddMember,
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.vote_proposal(&member_a, &add_id);
+    dao.vote_proposal(&member_b, &add_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.evaluate_add_member_proposal(&member_a, &add_id);
+    dao.consent_proposal(&a, &add_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    let grant_id = mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &a,
+        Some(5300),
+        None,
+        types::proposal::Type::GrantVotingPower,
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5400;
+    });
+
+    dao.vote_proposal(&member_a, &grant_id);
+    dao.vote_proposal(&member_b, &grant_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.evaluate_grant_voting_proposal(&member_a, &grant_id);
+    dao.leave(&a);
+    dao.consent_proposal(&a, &grant_id);
+
+    assert_eq!(
+        dao.get_proposal_status(&grant_id),
+        types::proposal::Status::Lapsed
+    );
+    assert_eq!(dao.has_voting_power(&a), false);
+}
+
+#[test]
+#[should_panic(expected = "Consent period has ended")]
+fn add_member_proposal_consent_lapsed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+    let a = Address::generate(&env);
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let proposal_id = mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &a,
+        None,
+        None,
+        types::proposal::Type::AddMember,
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.vote_proposal(&member_a, &proposal_id);
+    dao.vote_proposal(&member_b, &proposal_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.evaluate_add_member_proposal(&member_a, &proposal_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000 + 86401;
+    });
+
+    dao.consent_proposal(&a, &proposal_id);
+}
+
+#[test]
+fn remove_member_proposal_min_members() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+    let member_c = initial_members.get(2).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let remove_b = mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &member_b,
+        None,
+        None,
+        types::proposal::Type::RemoveMember,
+    );
+
+    let remove_c = mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &member_c,
+        None,
+        None,
+        types::proposal::Type::RemoveMember,
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    for proposal_id in [&remove_b, &remove_c] {
+        dao.vote_proposal(&member_a, proposal_id);
+        dao.vote_proposal(&member_b, proposal_id);
+    }
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.evaluate_remove_member_proposal(&member_a, &remove_b);
+    dao.evaluate_remove_member_proposal(&member_a, &remove_c);
+
+    // the proposal is rejected right away instead of being accepted first
+    let topics: Vec<Val> = (
+        Symbol::new(&env, "status_changed"),
+        event::LIFECYCLE_VERSION,
+        remove_c.clone(),
+    )
+        .into_val(&env);
+
+    assert_eq!(
+        env.events()
+            .all()
+            .iter()
+            .filter(|(_, event_topics, _)| *event_topics == topics)
+            .map(|(_, _, data)| data.try_into_val(&env).unwrap())
+            .collect::<std::vec::Vec<(types::proposal::Status, types::proposal::Status)>>(),
+        [(
+            types::proposal::Status::Ended,
+            types::proposal::Status::Rejected
+        )]
+    );
+
+    // removing member c as well would leave the DAO with fewer than min_members
+    assert_eq!(
+        dao.get_proposal_status(&remove_c),
+        types::proposal::Status::Rejected
+    );
+    assert_eq!(dao.get_member_count(), 2);
+}
+
+#[test]
+#[should_panic(expected = "DAO must keep atleast min_voting_members voting members")]
+fn revoke_voting_proposal_min_voting_members() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+
+    let mut metadata = mock_dao_metadata(&env);
+    metadata.min_voting_members = 3;
+
+    let dao = create_dao_with_metadata(env.clone(), &initial_members, metadata);
+
+    mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &member_a,
+        None,
+        None,
+        types::proposal::Type::RevokeVotingPower,
+    );
+}
+
+#[test]
+fn batch_add_member_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let proposal_id = dao.batch_add_proposal(&types::proposal::BatchAddMember {
+        metadata: mock_proposal_metadata(&env, &member_a, None, None),
+        candidates: vec![&env, a.clone(), b.clone()],
+    });
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.vote_proposal(&member_a, &proposal_id);
+    dao.vote_proposal(&member_b, &proposal_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.evaluate_batch_add_proposal(&member_a, &proposal_id);
+    dao.consent_proposal(&a, &proposal_id);
+
+    // the batch is only applied once every candidate consented
+    assert!(!dao.get_members(&0, &10).contains(&a));
+
+    dao.consent_proposal(&b, &proposal_id);
+
+    let members = dao.get_members(&0, &10);
+
+    assert!(members.contains(&a));
+    assert!(members.contains(&b));
+
+    let proposal_id = dao.batch_grant_proposal(&types::proposal::BatchGrantVotingPower {
+        metadata: mock_proposal_metadata(&env, &member_a, Some(27100), Some(40000)),
+        candidates: vec![&env, a.clone(), b.clone()],
+    });
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27200;
+    });
+
+    for voter in initial_members.iter() {
+        dao.vote_proposal(&voter, &proposal_id);
+    }
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 45000;
+    });
+
+    dao.evaluate_batch_grant_proposal(&member_a, &proposal_id);
+    dao.consent_proposal(&a, &proposal_id);
+    dao.consent_proposal(&b, &proposal_id);
+
+    assert!(dao.has_voting_power(&a));
+    assert!(dao.has_voting_power(&b));
+}
+
+#[test]
+#[should_panic(expected = "Already a member")]
+fn batch_add_member_proposal_already_member() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    dao.batch_add_proposal(&types::proposal::BatchAddMember {
+        metadata: mock_proposal_metadata(&env, &member_a, None, None),
+        candidates: vec![&env, Address::generate(&env), member_b],
+    });
+}
+
+#[test]
+fn batch_remove_member_proposal_left_member() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let members = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    let member_a = members.get(0).unwrap();
+    let member_b = members.get(1).unwrap();
+    let member_d = members.get(3).unwrap();
+
+    let dao = create_dao(env.clone(), &members);
+
+    let proposal_id = dao.batch_remove_proposal(&types::proposal::BatchRemoveMember {
+        metadata: mock_proposal_metadata(&env, &member_a, None, None),
+        candidates: vec![&env, member_d.clone()],
+    });
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.vote_proposal(&member_a, &proposal_id);
+    dao.vote_proposal(&member_b, &proposal_id);
+
+    dao.leave(&member_d);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    // the candidate already left, so the proposal is rejected instead of failing to be applied
+    dao.evaluate_batch_remove_proposal(&member_a, &proposal_id);
+
+    assert_eq!(
+        dao.get_proposal_status(&proposal_id),
+        types::proposal::Status::Rejected
+    );
+}
+
+#[test]
+fn batch_revoke_voting_proposal_left_member() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let members = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    let member_a = members.get(0).unwrap();
+    let member_b = members.get(1).unwrap();
+    let member_d = members.get(3).unwrap();
+
+    let dao = create_dao(env.clone(), &members);
+
+    let proposal_id = dao.batch_revoke_proposal(&types::proposal::BatchRevokeVotingPower {
+        metadata: mock_proposal_metadata(&env, &member_a, None, None),
+        candidates: vec![&env, member_d.clone()],
+    });
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.vote_proposal(&member_a, &proposal_id);
+    dao.vote_proposal(&member_b, &proposal_id);
+
+    dao.leave(&member_d);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    // the candidate already left, so the proposal is rejected instead of failing to be applied
+    dao.evaluate_batch_revoke_proposal(&member_a, &proposal_id);
+
+    assert_eq!(
+        dao.get_proposal_status(&proposal_id),
+        types::proposal::Status::Rejected
+    );
+}
+
+#[test]
+#[should_panic(expected = "ttl_extend_to must not exceed the maximum TTL")]
+fn initialize_ttl_extend_to_exceeds_max_ttl() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let mut metadata = mock_dao_metadata(&env);
+    metadata.ttl_extend_to = env.storage().max_ttl() + 1;
+
+    create_dao_with_metadata(env.clone(), &get_initial_members(&env), metadata);
+}
+
+#[test]
+fn extend_ttl() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_members = get_initial_members(&env);
+    let member = initial_members.get(0).unwrap();
+    let a = Address::generate(&env);
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let proposal_id = mock_proposal(
+        &env,
+        &dao,
+        &member,
+        &a,
+        None,
+        None,
+        types::proposal::Type::AddMember,
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5400;
+    });
+
+    dao.vote_proposal(&member, &proposal_id);
+
+    let metadata = mock_dao_metadata(&env);
+
+    // entries not extended in between would be archived by the second ledger advance
+    env.ledger().with_mut(|li| {
+        li.sequence_number += metadata.ttl_extend_to - metadata.ttl_threshold / 2;
+    });
+
+    dao.extend_ttl(&0, &10);
+    dao.extend_proposal_ttl(&proposal_id, &0, &10);
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number += metadata.ttl_extend_to - metadata.ttl_threshold / 2;
+    });
+
+    assert_eq!(dao.get_members(&0, &10), initial_members);
+    assert!(dao.has_voting_power(&member));
+    assert_eq!(dao.get_add_member_proposal(&proposal_id).candidate, a);
+    assert_eq!(
+        dao.get_proposal_votes(&proposal_id, &0, &10),
+        vec![&env, member]
+    );
+}
+
+#[test]
+fn extend_ttl_secret_ballot_commitment() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member = initial_members.get(0).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let mut proposal_metadata = mock_proposal_metadata(&env, &member, None, None);
+    proposal_metadata.ballot = types::proposal::Ballot::Secret(3600);
+
+    let proposal_id = dao.general_purpose_proposal(&types::proposal::GeneralPurpose {
+        metadata: proposal_metadata,
+        actions: String::from_str(&env, "mock actions"),
+    });
+
+    let salt = BytesN::from_array(&env, &[7_u8; 32]);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.commit_vote_proposal(
+        &member,
+        &proposal_id,
+        &proposal::commitment_hash(&env, true, salt.clone()),
+    );
+
+    let metadata = mock_dao_metadata(&env);
+
+    // entries not extended in between would be archived by the second ledger advance
+    env.ledger().with_mut(|li| {
+        li.sequence_number += metadata.ttl_extend_to - metadata.ttl_threshold / 2;
+    });
+
+    dao.extend_ttl(&0, &10);
+    dao.extend_proposal_ttl(&proposal_id, &0, &10);
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number += metadata.ttl_extend_to - metadata.ttl_threshold / 2;
+        li.timestamp = 27000;
+    });
+
+    dao.reveal_vote_proposal(&member, &proposal_id, &true, &salt);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 30000;
+    });
+
+    assert_eq!(
+        dao.get_proposal_votes(&proposal_id, &0, &10),
+        vec![&env, member]
+    );
+}
+
+fn accepted_general_purpose_proposal<'a>(env: &Env) -> (DaoContractClient<'a>, BytesN<32>) {
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let proposal_id = mock_proposal(
+        env,
+        &dao,
+        &member_a,
+        &member_a,
+        None,
+        None,
+        types::proposal::Type::GeneralPurpose,
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.vote_proposal(&member_a, &proposal_id);
+    dao.vote_proposal(&member_b, &proposal_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.evaluate_general_proposal(&member_a, &proposal_id);
+
+    (dao, proposal_id)
+}
+
+#[test]
+fn proposal_prune() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (dao, proposal_id) = accepted_general_purpose_proposal(&env);
+
+    let summary = dao.get_proposal_summary(&proposal_id);
+
+    assert_eq!(summary.proposal_type, types::proposal::Type::GeneralPurpose);
+    assert_eq!(summary.status, types::proposal::Status::Accepted);
+    assert_eq!(summary.votes, 2);
+    assert_eq!(summary.tally, 2);
+    assert_eq!(summary.finalized_at, 27000);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000 + mock_dao_metadata(&env).prune_delay + 1;
+    });
+
+    assert_eq!(dao.prune_proposal(&proposal_id, &10), 0);
+
+    assert_eq!(dao.get_proposal_summary(&proposal_id), summary);
+    assert_eq!(
+        dao.get_proposal_status(&proposal_id),
+        types::proposal::Status::Accepted
+    );
+    assert!(dao.try_get_proposal_votes(&proposal_id, &0, &10).is_err());
+    assert!(dao.try_prune_proposal(&proposal_id, &10).is_err());
+}
+
+#[test]
+fn proposal_prune_paginated() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (dao, proposal_id) = accepted_general_purpose_proposal(&env);
+
+    let summary = dao.get_proposal_summary(&proposal_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000 + mock_dao_metadata(&env).prune_delay + 1;
+    });
+
+    assert_eq!(dao.prune_proposal(&proposal_id, &1), 1);
+
+    // the proposal counts as pruned while its remaining votes are removed
+    assert_eq!(dao.get_proposal_summary(&proposal_id), summary);
+    assert_eq!(
+        dao.get_proposal_status(&proposal_id),
+        types::proposal::Status::Accepted
+    );
+
+    env.as_contract(&dao.address, || {
+        let storage = env.storage().persistent();
+
+        assert!(storage.has(&types::data_key::Proposal::VoteAt(proposal_id.clone(), 0)));
+        assert!(!storage.has(&types::data_key::Proposal::VoteAt(proposal_id.clone(), 1)));
+    });
+
+    assert_eq!(dao.prune_proposal(&proposal_id, &1), 0);
+
+    env.as_contract(&dao.address, || {
+        let storage = env.storage().persistent();
+
+        assert!(!storage.has(&types::data_key::Proposal::VoteAt(proposal_id.clone(), 0)));
+        assert!(!storage.has(&types::data_key::Proposal::Metadata(proposal_id.clone())));
+    });
+
+    assert!(dao.try_prune_proposal(&proposal_id, &1).is_err());
+}
+
+#[test]
+fn proposal_prune_secret_ballot() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let mut metadata = mock_proposal_metadata(&env, &member_a, None, None);
+    metadata.ballot = types::proposal::Ballot::Secret(3600);
+
+    let proposal_id = dao.general_purpose_proposal(&types::proposal::GeneralPurpose {
+        metadata,
+        actions: String::from_str(&env, "mock actions"),
+    });
+
+    let salt = BytesN::from_array(&env, &[7_u8; 32]);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.commit_vote_proposal(
+        &member_a,
+        &proposal_id,
+        &proposal::commitment_hash(&env, true, salt.clone()),
+    );
+    dao.commit_vote_proposal(
+        &member_b,
+        &proposal_id,
+        &proposal::commitment_hash(&env, true, salt.clone()),
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.reveal_vote_proposal(&member_a, &proposal_id, &true, &salt);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 30000;
+    });
+
+    dao.evaluate_general_proposal(&member_a, &proposal_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 30000 + mock_dao_metadata(&env).prune_delay + 1;
+    });
+
+    assert_eq!(dao.prune_proposal(&proposal_id, &10), 0);
+
+    // the commitment of member b was never revealed
+    env.as_contract(&dao.address, || {
+        let storage = env.storage().persistent();
+
+        assert!(!storage.has(&types::data_key::Proposal::Commitment(
+            proposal_id.clone(),
+            member_b
+        )));
+        assert!(!storage.has(&types::data_key::Proposal::CommitmentAt(
+            proposal_id.clone(),
+            1
+        )));
+        assert!(!storage.has(&types::data_key::Proposal::CommitmentCount(
+            proposal_id.clone()
+        )));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Prune delay has not passed yet")]
+fn proposal_prune_too_early() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (dao, proposal_id) = accepted_general_purpose_proposal(&env);
+
+    assert_eq!(dao.prune_proposal(&proposal_id, &10), 0);
+}
+
+#[test]
+fn proposal_lifecycle_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member = initial_members.get(0).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let proposal_id = mock_proposal(
+        &env,
+        &dao,
+        &member,
+        &member,
+        None,
+        None,
+        types::proposal::Type::GeneralPurpose,
+    );
+
+    let topics: Vec<Val> = (
+        Symbol::new(&env, "proposal_created"),
+        event::LIFECYCLE_VERSION,
+        proposal_id.clone(),
+    )
+        .into_val(&env);
+    let data: Val = (
+        types::proposal::Type::GeneralPurpose,
+        member.clone(),
+        5100_u64,
+        26000_u64,
+    )
+        .into_val(&env);
+
+    assert!(env
+        .events()
+        .all()
+        .contains((dao.address.clone(), topics, data)));
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.vote_proposal(&member, &proposal_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.evaluate_general_proposal(&member, &proposal_id);
+
+    let events = env.events().all();
+
+    let topics: Vec<Val> = (
+        Symbol::new(&env, "status_changed"),
+        event::LIFECYCLE_VERSION,
+        proposal_id.clone(),
+    )
+        .into_val(&env);
+    let data: Val = (
+        types::proposal::Status::Ended,
+        types::proposal::Status::Rejected,
+    )
+        .into_val(&env);
+
+    assert!(events.contains((dao.address.clone(), topics, data)));
+
+    let topics: Vec<Val> = (
+        Symbol::new(&env, "proposal_finalized"),
+        event::LIFECYCLE_VERSION,
+        proposal_id,
+    )
+        .into_val(&env);
+    let data: Val = (types::proposal::Status::Rejected, 1_u32, 1_u32, 0_u32).into_val(&env);
+
+    assert!(events.contains((dao.address.clone(), topics, data)));
+}
+
+fn sha256_xdr(env: &Env, value: impl IntoVal<Env, Val>) -> BytesN<32> {
+    let value: Val = value.into_val(env);
+
+    env.crypto().sha256(&value.to_xdr(env))
+}
+
+#[test]
+fn proposal_id_precomputed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_members = get_initial_members(&env);
+    let member = initial_members.get(0).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let proposal = types::proposal::GeneralPurpose {
+        metadata: mock_proposal_metadata(&env, &member, None, None),
+        actions: String::from_str(&env, "mock actions"),
+    };
+
+    let nonce = dao.get_proposal_nonce(&member);
+    let body_hash = sha256_xdr(&env, proposal.clone());
+    let proposal_id = sha256_xdr(&env, (body_hash, member.clone(), nonce));
+
+    assert_eq!(dao.general_purpose_proposal(&proposal), proposal_id);
+    assert_eq!(dao.get_proposal_nonce(&member), nonce + 1);
+}
+
+#[test]
+#[should_panic(expected = "Duplicate proposal")]
+fn proposal_duplicate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_members = get_initial_members(&env);
+    let member = initial_members.get(0).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let proposal = types::proposal::GeneralPurpose {
+        metadata: mock_proposal_metadata(&env, &member, None, None),
+        actions: String::from_str(&env, "mock actions"),
+    };
+
+    dao.general_purpose_proposal(&proposal);
+    dao.general_purpose_proposal(&proposal);
+}
+
+fn create_dao_with_bond<'a>(
+    env: &Env,
+    members: &Vec<Address>,
+) -> (DaoContractClient<'a>, soroban_sdk::token::Client<'a>) {
+    let asset = env.register_stellar_asset_contract(Address::generate(env));
+
+    let mut metadata = mock_dao_metadata(env);
+    metadata.proposal_bond = dao::Bond::Asset(asset.clone(), 10);
+
+    let dao = create_dao_with_metadata(env.clone(), members, metadata);
+
+    for member in members.iter() {
+        soroban_sdk::token::StellarAssetClient::new(env, &asset).mint(&member, &100);
+    }
+
+    (dao, soroban_sdk::token::Client::new(env, &asset))
+}
+
+#[test]
+fn proposal_bond_refunded() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+
+    let (dao, token) = create_dao_with_bond(&env, &initial_members);
+
+    let proposal_id = mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &member_a,
+        None,
+        None,
+        types::proposal::Type::GeneralPurpose,
+    );
+
+    assert_eq!(token.balance(&member_a), 90);
+    assert_eq!(token.balance(&dao.address), 10);
+    assert_eq!(
+        dao.get_proposal_bond(&proposal_id),
+        dao::Bond::Asset(token.address.clone(), 10)
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.vote_proposal(&member_a, &proposal_id);
+    dao.vote_proposal(&member_b, &proposal_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.evaluate_general_proposal(&member_a, &proposal_id);
+
+    assert_eq!(token.balance(&member_a), 100);
+    assert_eq!(token.balance(&dao.address), 0);
+    assert_eq!(dao.get_proposal_bond(&proposal_id), dao::Bond::Disabled);
+}
+
+#[test]
+fn proposal_bond_forfeited() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member = initial_members.get(0).unwrap();
+
+    let (dao, token) = create_dao_with_bond(&env, &initial_members);
+
+    let proposal_id = mock_proposal(
+        &env,
+        &dao,
+        &member,
+        &member,
+        None,
+        None,
+        types::proposal::Type::GeneralPurpose,
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.evaluate_general_proposal(&member, &proposal_id);
+
+    assert_eq!(
+        dao.get_proposal_status(&proposal_id),
+        types::proposal::Status::Rejected
+    );
+    assert_eq!(token.balance(&member), 90);
+    assert_eq!(token.balance(&dao.address), 10);
+}
+
+/// Grants `council` the council role and restricts votes on general purpose proposals to it.
+fn permit_only_council_to_vote(env: &Env, dao: &DaoContractClient, council: &Address) {
+    let members = dao.get_members(&0, &10);
+    let member_a = members.get(0).unwrap();
+
+    let grant_role_id = mock_proposal(
+        env,
+        dao,
+        &member_a,
+        council,
+        None,
+        None,
+        types::proposal::Type::GrantRole,
+    );
+    let set_permission_id = dao.set_permission_proposal(&types::proposal::SetPermission {
+        metadata: mock_proposal_metadata(env, &member_a, None, None),
+        permission: types::role::Permission {
+            proposal_type: types::proposal::Type::GeneralPurpose,
+            action: types::role::Action::Vote,
+            roles: vec![env, Symbol::new(env, "council")],
+        },
+    });
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    for id in [&grant_role_id, &set_permission_id] {
+        for voter in members.iter() {
+            dao.vote_proposal(&voter, id);
+        }
+    }
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.evaluate_grant_role_proposal(&member_a, &grant_role_id);
+    dao.evaluate_set_permission_proposal(&member_a, &set_permission_id);
+}
+
+#[test]
+fn proposal_bond_refunded_permitted_voters() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_c = initial_members.get(2).unwrap();
+
+    let asset = env.register_stellar_asset_contract(Address::generate(&env));
+
+    let mut metadata = mock_dao_metadata(&env);
+    metadata.proposal_bond = dao::Bond::Asset(asset.clone(), 10);
+    metadata.bond_min_turnout_percentage = 40;
+
+    let dao = create_dao_with_metadata(env.clone(), &initial_members, metadata);
+    let token = soroban_sdk::token::Client::new(&env, &asset);
+
+    for member in initial_members.iter() {
+        soroban_sdk::token::StellarAssetClient::new(&env, &asset).mint(&member, &100);
+    }
+
+    permit_only_council_to_vote(&env, &dao, &member_c);
+
+    let mut metadata = mock_proposal_metadata(&env, &member_a, Some(27100), Some(40000));
+    metadata.ballot = types::proposal::Ballot::Optimistic;
+
+    let proposal_id = dao.general_purpose_proposal(&types::proposal::GeneralPurpose {
+        metadata,
+        actions: String::from_str(&env, "mock actions"),
+    });
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27200;
+    });
+
+    // the council is the only member permitted to object, one of three voting members in total
+    dao.object_proposal(&member_c, &proposal_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 45000;
+    });
+
+    dao.evaluate_general_proposal(&member_a, &proposal_id);
+
+    assert_eq!(
+        dao.get_proposal_status(&proposal_id),
+        types::proposal::Status::Rejected
+    );
+    assert_eq!(token.balance(&member_a), 100);
+}
+
+#[test]
+fn proposer_status() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member = initial_members.get(0).unwrap();
+
+    let mut metadata = mock_dao_metadata(&env);
+    metadata.max_open_proposals = 1;
+    metadata.proposal_cooldown = 50;
+
+    let dao = create_dao_with_metadata(env.clone(), &initial_members, metadata);
+
+    assert_eq!(
+        dao.get_proposer_status(&member),
+        types::proposal::ProposerStatus {
+            open_proposals: 0,
+            next_proposal_at: 0,
+        }
+    );
+
+    mock_proposal(
+        &env,
+        &dao,
+        &member,
+        &member,
+        None,
+        None,
+        types::proposal::Type::GeneralPurpose,
+    );
+
+    assert_eq!(
+        dao.get_proposer_status(&member),
+        types::proposal::ProposerStatus {
+            open_proposals: 1,
+            next_proposal_at: 26001,
+        }
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 26001;
+    });
+
+    assert_eq!(
+        dao.get_proposer_status(&member),
+        types::proposal::ProposerStatus {
+            open_proposals: 0,
+            next_proposal_at: 5050,
+        }
+    );
+
+    mock_proposal(
+        &env,
+        &dao,
+        &member,
+        &member,
+        Some(26100),
+        Some(30000),
+        types::proposal::Type::GeneralPurpose,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Proposer has too many open proposals")]
+fn proposer_open_proposals_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_members = get_initial_members(&env);
+    let member = initial_members.get(0).unwrap();
+    let a = Address::generate(&env);
+
+    let mut metadata = mock_dao_metadata(&env);
+    metadata.max_open_proposals = 1;
+
+    let dao = create_dao_with_metadata(env.clone(), &initial_members, metadata);
+
+    for proposal_type in [
+        types::proposal::Type::GeneralPurpose,
+        types::proposal::Type::AddMember,
+    ] {
+        mock_proposal(&env, &dao, &member, &a, None, None, proposal_type);
+    }
+}
+
+#[test]
+fn proposer_open_proposals_unlimited() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_members = get_initial_members(&env);
+    let member = initial_members.get(0).unwrap();
+    let a = Address::generate(&env);
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    for proposal_type in [
+        types::proposal::Type::GeneralPurpose,
+        types::proposal::Type::AddMember,
+    ] {
+        mock_proposal(&env, &dao, &member, &a, None, None, proposal_type);
+    }
+
+    // without a limit, open proposals are not tracked
+    env.as_contract(&dao.address, || {
+        assert!(!env
+            .storage()
+            .persistent()
+            .has(&types::data_key::Dao::OpenProposals(member.clone())));
+        assert!(env
+            .storage()
+            .persistent()
+            .has(&types::data_key::Dao::LastProposal(member)));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Proposal cooldown has not passed yet")]
+fn proposer_cooldown() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_members = get_initial_members(&env);
+    let member = initial_members.get(0).unwrap();
+    let a = Address::generate(&env);
+
+    let mut metadata = mock_dao_metadata(&env);
+    metadata.proposal_cooldown = 3600;
+
+    let dao = create_dao_with_metadata(env.clone(), &initial_members, metadata);
+
+    for proposal_type in [
+        types::proposal::Type::GeneralPurpose,
+        types::proposal::Type::AddMember,
+    ] {
+        mock_proposal(&env, &dao, &member, &a, None, None, proposal_type);
+    }
+}
+
+#[test]
+fn proposal_amend() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_members = get_initial_members(&env);
+    let member = initial_members.get(0).unwrap();
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let proposal_id = mock_proposal(
+        &env,
+        &dao,
+        &member,
+        &a,
+        None,
+        None,
+        types::proposal::Type::AddMember,
+    );
+
+    let name = String::from_str(&env, "amended name");
+    let description = String::from_str(&env, "amended description");
+
+    dao.amend_proposal(
+        &member,
+        &proposal_id,
+        &types::proposal::Amendment::Text(name.clone(), description.clone()),
+    );
+    dao.amend_proposal(
+        &member,
+        &proposal_id,
+        &types::proposal::Amendment::Candidate(b.clone()),
+    );
+
+    let proposal = dao.get_add_member_proposal(&proposal_id);
+
+    assert_eq!(proposal.metadata.name, name);
+    assert_eq!(proposal.metadata.description, description);
+    assert_eq!(proposal.candidate, b);
+
+    let revisions = dao.get_proposal_revisions(&proposal_id);
+
+    assert_eq!(revisions.len(), 2);
+    assert_eq!(
+        revisions.get(0).unwrap().body_hash,
+        revisions.get(1).unwrap().previous_hash
+    );
+    assert_eq!(
+        revisions.get(1).unwrap().body_hash,
+        sha256_xdr(&env, proposal)
+    );
+}
+
+#[test]
+fn proposal_amend_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_members = get_initial_members(&env);
+    let member = initial_members.get(0).unwrap();
+    let candidates = vec![&env, Address::generate(&env), Address::generate(&env)];
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let proposal_id = dao.batch_add_proposal(&types::proposal::BatchAddMember {
+        metadata: mock_proposal_metadata(&env, &member, None, None),
+        candidates: candidates.clone(),
+    });
+
+    let name = String::from_str(&env, "amended name");
+    let description = String::from_str(&env, "amended description");
+
+    dao.amend_proposal(
+        &member,
+        &proposal_id,
+        &types::proposal::Amendment::Text(name.clone(), description.clone()),
+    );
+
+    let proposal = dao.get_batch_add_proposal(&proposal_id);
+
+    assert_eq!(proposal.metadata.name, name);
+    assert_eq!(proposal.metadata.description, description);
+    assert_eq!(proposal.candidates, candidates);
+    assert_eq!(
+        dao.get_proposal_revisions(&proposal_id)
+            .get(0)
+            .unwrap()
+            .body_hash,
+        sha256_xdr(&env, proposal)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Proposal can only be amended before it starts")]
+fn proposal_amend_after_start() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_members = get_initial_members(&env);
+    let member = initial_members.get(0).unwrap();
+    let a = Address::generate(&env);
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let proposal_id = mock_proposal(
+        &env,
+        &dao,
+        &member,
+        &a,
+        None,
+        None,
+        types::proposal::Type::GeneralPurpose,
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 5400);
+
+    dao.amend_proposal(
+        &member,
+        &proposal_id,
+        &types::proposal::Amendment::Text(
+            String::from_str(&env, "amended name"),
+            String::from_str(&env, "amended description"),
+        ),
+    );
+}
+
+fn temperature_check_proposal(
+    env: &Env,
+    dao: &DaoContractClient,
+    proposer: &Address,
+) -> BytesN<32> {
+    let mut metadata = mock_proposal_metadata(env, proposer, None, None);
+    metadata.temperature_check = types::proposal::TemperatureCheck::Signal(3600, 50);
+
+    dao.general_purpose_proposal(&types::proposal::GeneralPurpose {
+        metadata,
+        actions: String::from_str(env, "mock actions"),
+    })
+}
+
+#[test]
+fn temperature_check_passed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let proposal_id = temperature_check_proposal(&env, &dao, &member_a);
+
+    assert_eq!(
+        dao.get_proposal_status(&proposal_id),
+        types::proposal::Status::NotStarted
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 2000;
+    });
+
+    assert_eq!(
+        dao.get_proposal_status(&proposal_id),
+        types::proposal::Status::TemperatureCheck
+    );
+
+    dao.signal_proposal(&member_a, &proposal_id);
+    dao.signal_proposal(&member_b, &proposal_id);
+
+    assert_eq!(dao.get_proposal_signals(&proposal_id).len(), 2);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    assert_eq!(
+        dao.get_proposal_status(&proposal_id),
+        types::proposal::Status::Active
+    );
+
+    dao.vote_proposal(&member_a, &proposal_id);
+    dao.vote_proposal(&member_b, &proposal_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.evaluate_general_proposal(&member_a, &proposal_id);
+
+    assert_eq!(
+        dao.get_proposal_status(&proposal_id),
+        types::proposal::Status::Accepted
+    );
+}
+
+#[test]
+fn temperature_check_passed_permitted_voters() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5000;
+    });
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_c = initial_members.get(2).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    permit_only_council_to_vote(&env, &dao, &member_c);
+
+    let mut metadata = mock_proposal_metadata(&env, &member_a, Some(30700), Some(50000));
+    metadata.temperature_check = types::proposal::TemperatureCheck::Signal(3600, 50);
+
+    let proposal_id = dao.general_purpose_proposal(&types::proposal::GeneralPurpose {
+        metadata,
+        actions: String::from_str(&env, "mock actions"),
+    });
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 28000;
+    });
+
+    // the council is the only member permitted to vote, so its signal is enough
+    dao.signal_proposal(&member_c, &proposal_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 30800;
+    });
+
+    assert_eq!(
+        dao.get_proposal_status(&proposal_id),
+        types::proposal::Status::Active
+    );
+}
+
+#[test]
+fn temperature_check_failed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let proposal_id = temperature_check_proposal(&env, &dao, &member_a);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 2000;
+    });
+
+    dao.signal_proposal(&member_a, &proposal_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    assert_eq!(
+        dao.get_proposal_status(&proposal_id),
+        types::proposal::Status::TemperatureCheckFailed
+    );
+    assert!(dao.try_vote_proposal(&member_a, &proposal_id).is_err());
+
+    dao.evaluate_general_proposal(&member_a, &proposal_id);
+
+    assert_eq!(
+        dao.get_proposal_status(&proposal_id),
+        types::proposal::Status::Rejected
+    );
+}
+
+#[test]
+#[should_panic(expected = "Temperature check is not open")]
+fn temperature_check_not_open() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_members = get_initial_members(&env);
+    let member = initial_members.get(0).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let proposal_id = temperature_check_proposal(&env, &dao, &member);
+
+    dao.signal_proposal(&member, &proposal_id);
+}
+
+fn dependent_proposal(
+    env: &Env,
+    dao: &DaoContractClient,
+    proposer: &Address,
+    dependencies: Vec<BytesN<32>>,
+) -> BytesN<32> {
+    let mut metadata = mock_proposal_metadata(env, proposer, None, None);
+    metadata.dependencies = dependencies;
+
+    dao.general_purpose_proposal(&types::proposal::GeneralPurpose {
+        metadata,
+        actions: String::from_str(env, "dependent actions"),
+    })
+}
+
+#[test]
+fn proposal_dependencies() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let prerequisite_id = mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &member_a,
+        None,
+        None,
+        types::proposal::Type::GeneralPurpose,
+    );
+    let proposal_id =
+        dependent_proposal(&env, &dao, &member_a, vec![&env, prerequisite_id.clone()]);
+
+    assert_eq!(
+        dao.get_proposal_dependencies(&proposal_id),
+        vec![&env, prerequisite_id.clone()]
+    );
+    assert_eq!(
+        dao.get_proposal_dependents(&prerequisite_id),
+        vec![&env, proposal_id.clone()]
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    for id in [prerequisite_id.clone(), proposal_id.clone()] {
+        dao.vote_proposal(&member_a, &id);
+        dao.vote_proposal(&member_b, &id);
+    }
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    assert!(dao
+        .try_evaluate_general_proposal(&member_a, &proposal_id)
+        .is_err());
+
+    dao.evaluate_general_proposal(&member_a, &prerequisite_id);
+    dao.evaluate_general_proposal(&member_a, &proposal_id);
+
+    assert_eq!(
+        dao.get_proposal_status(&proposal_id),
+        types::proposal::Status::Accepted
+    );
+}
+
+#[test]
+fn proposal_dependency_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let prerequisite_id = mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &member_a,
+        None,
+        None,
+        types::proposal::Type::GeneralPurpose,
+    );
+    let proposal_id =
+        dependent_proposal(&env, &dao, &member_a, vec![&env, prerequisite_id.clone()]);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    dao.vote_proposal(&member_a, &proposal_id);
+    dao.vote_proposal(&member_b, &proposal_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.evaluate_general_proposal(&member_a, &prerequisite_id);
+    dao.evaluate_general_proposal(&member_a, &proposal_id);
+
+    assert_eq!(
+        dao.get_proposal_status(&proposal_id),
+        types::proposal::Status::Rejected
+    );
+}
+
+#[test]
+fn proposal_dependency_add_member_grant_voting_power() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+    let a = Address::generate(&env);
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let add_member_id = mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &a,
+        None,
+        None,
+        types::proposal::Type::AddMember,
+    );
+
+    let mut metadata = mock_proposal_metadata(&env, &member_a, None, None);
+    metadata.dependencies = vec![&env, add_member_id.clone()];
+
+    // the candidate is not a member yet, so it is only checked when the proposal is evaluated
+    let grant_id = dao.grant_voting_proposal(&types::proposal::GrantVotingPower {
+        metadata,
+        candidate: a.clone(),
+    });
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    for id in [add_member_id.clone(), grant_id.clone()] {
+        dao.vote_proposal(&member_a, &id);
+        dao.vote_proposal(&member_b, &id);
+    }
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.evaluate_add_member_proposal(&member_a, &add_member_id);
+
+    // the dependency is accepted but the candidate has not consented yet
+    assert!(dao
+        .try_evaluate_grant_voting_proposal(&member_a, &grant_id)
+        .is_err());
+
+    dao.consent_proposal(&a, &add_member_id);
+    dao.evaluate_grant_voting_proposal(&member_a, &grant_id);
+
+    assert_eq!(
+        dao.get_proposal_status(&grant_id),
+        types::proposal::Status::Accepted
+    );
+
+    dao.consent_proposal(&a, &grant_id);
+
+    assert!(dao.has_voting_power(&a));
+}
+
+#[test]
+fn proposal_dependency_batch_add_member_batch_grant_voting_power() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_b = initial_members.get(1).unwrap();
+    let candidates = vec![&env, Address::generate(&env), Address::generate(&env)];
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let add_id = dao.batch_add_proposal(&types::proposal::BatchAddMember {
+        metadata: mock_proposal_metadata(&env, &member_a, None, None),
+        candidates: candidates.clone(),
+    });
+
+    let mut metadata = mock_proposal_metadata(&env, &member_a, None, None);
+    metadata.dependencies = vec![&env, add_id.clone()];
+
+    // the candidates are not members yet, so they are only checked when the proposal is evaluated
+    let grant_id = dao.batch_grant_proposal(&types::proposal::BatchGrantVotingPower {
+        metadata,
+        candidates: candidates.clone(),
+    });
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5200;
+    });
+
+    for id in [add_id.clone(), grant_id.clone()] {
+        dao.vote_proposal(&member_a, &id);
+        dao.vote_proposal(&member_b, &id);
+    }
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 27000;
+    });
+
+    dao.evaluate_batch_add_proposal(&member_a, &add_id);
+
+    for candidate in candidates.iter() {
+        dao.consent_proposal(&candidate, &add_id);
+    }
+
+    dao.evaluate_batch_grant_proposal(&member_a, &grant_id);
+
+    assert_eq!(
+        dao.get_proposal_status(&grant_id),
+        types::proposal::Status::Accepted
+    );
+
+    for candidate in candidates.iter() {
+        dao.consent_proposal(&candidate, &grant_id);
+    }
+
+    for candidate in candidates.iter() {
+        assert!(dao.has_voting_power(&candidate));
+    }
+}
+
+#[test]
+#[should_panic(expected = "DAO must keep atleast min_members members")]
+fn proposal_dependency_remove_member_min_members() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_members = get_initial_members(&env);
+    let member_a = initial_members.get(0).unwrap();
+    let member_c = initial_members.get(2).unwrap();
+
+    let mut dao_metadata = mock_dao_metadata(&env);
+    dao_metadata.min_members = 3;
+
+    let dao = create_dao_with_metadata(env.clone(), &initial_members, dao_metadata);
+
+    let prerequisite_id = mock_proposal(
+        &env,
+        &dao,
+        &member_a,
+        &member_a,
+        None,
+        None,
+        types::proposal::Type::GeneralPurpose,
+    );
+
+    let mut metadata = mock_proposal_metadata(&env, &member_a, None, None);
+    metadata.dependencies = vec![&env, prerequisite_id];
+
+    // the candidate is checked at evaluation, but the DAO is already at min_members
+    dao.remove_member_proposal(&types::proposal::RemoveMember {
+        metadata,
+        candidate: member_c,
+    });
+}
+
+#[test]
+#[should_panic(expected = "Proposal has too many dependencies")]
+fn proposal_too_many_dependencies() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_members = get_initial_members(&env);
+    let member = initial_members.get(0).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let prerequisite_id = mock_proposal(
+        &env,
+        &dao,
+        &member,
+        &member,
+        None,
+        None,
+        types::proposal::Type::GeneralPurpose,
+    );
+
+    let mut dependencies = Vec::new(&env);
+
+    for _ in 0..=proposal::dependency::MAX_DEPENDENCIES {
+        dependencies.push_back(prerequisite_id.clone());
+    }
+
+    dependent_proposal(&env, &dao, &member, dependencies);
+}
+
+#[test]
+#[should_panic(expected = "Duplicate dependency")]
+fn proposal_duplicate_dependency() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_members = get_initial_members(&env);
+    let member = initial_members.get(0).unwrap();
+
+    let dao = create_dao(env.clone(), &initial_members);
+
+    let prerequisite_id = mock_proposal(
+        &env,
+        &dao,
+        &member,
+        &member,
+        None,
+        None,
+        types::proposal::Type::GeneralPurpose,
+    );
+
+    dependent_proposal(
+        &env,
+        &dao,
+        &member,
+        vec![&env, prerequisite_id.clone(), prerequisite_id],
+    );
+}
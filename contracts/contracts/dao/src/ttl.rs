@@ -0,0 +1,233 @@
+/// Module for keeping DAO storage alive on networks with state expiry.
+/// Persistent entries are read and written through [`get`] and [`set`], which extend their TTL
+/// using the thresholds from the DAO metadata, so entries in use are never archived.
+use core::fmt::Debug;
+
+use soroban_sdk::{BytesN, Env, IntoVal, TryFromVal, Val};
+
+use crate::dao_token;
+use crate::member;
+use crate::metadata;
+use crate::proposal;
+use crate::role;
+use crate::types;
+use crate::types::data_key;
+
+/// Reads a persistent entry and extends its TTL if it exists.
+///
+/// # Arguments
+///
+/// - `key` - The key of the entry.
+pub fn get<K, V>(env: &Env, key: &K) -> Option<V>
+where
+    K: IntoVal<Env, Val>,
+    V: TryFromVal<Env, Val>,
+    V::Error: Debug,
+{
+    let value = env.storage().persistent().get(key);
+
+    if value.is_some() {
+        extend_existing(env, key);
+    }
+
+    value
+}
+
+/// Writes a persistent entry and extends its TTL.
+///
+/// # Arguments
+///
+/// - `key` - The key of the entry.
+/// - `value` - The value to write.
+pub fn set<K, V>(env: &Env, key: &K, value: &V)
+where
+    K: IntoVal<Env, Val>,
+    V: IntoVal<Env, Val>,
+{
+    env.storage().persistent().set(key, value);
+
+    extend_existing(env, key);
+}
+
+/// Extends the TTL of a persistent entry if it exists.
+///
+/// # Arguments
+///
+/// - `key` - The key of the entry.
+pub fn extend<K>(env: &Env, key: &K)
+where
+    K: IntoVal<Env, Val>,
+{
+    if env.storage().persistent().has(key) {
+        extend_existing(env, key);
+    }
+}
+
+/// Extends the TTL of the DAO-wide entries and of a page of members, including their voting power.
+///
+/// # Arguments
+///
+/// - `offset` - The index of the first member to extend.
+/// - `limit` - The maximum number of members to extend.
+pub fn extend_dao(env: &Env, offset: u32, limit: u32) {
+    for key in [
+        data_key::Dao::Metadata,
+        data_key::Dao::TokenContractId,
+        data_key::Dao::Members,
+        data_key::Dao::MemberCount,
+        data_key::Dao::Guardians,
+        data_key::Dao::PausedUntil,
+        data_key::Dao::PauseVotes,
+        data_key::Dao::UnpauseVotes,
+        data_key::Dao::VoterRoles,
+        data_key::Dao::Permissions,
+    ] {
+        extend(env, &key);
+    }
+
+    if let types::dao::Bond::Asset(asset, _) = metadata::dao::read(env).proposal_bond {
+        extend(env, &data_key::Dao::BondEscrow(asset));
+    }
+
+    for (proposal_type, action) in role::read_permissions(env) {
+        extend(env, &data_key::Dao::Permission(proposal_type, action));
+    }
+
+    let members = member::read_members(env, offset, limit);
+
+    for member in members.iter() {
+        extend(env, &data_key::Dao::Member(member.clone()));
+        extend(env, &data_key::Dao::Profile(member.clone()));
+        extend(env, &data_key::Dao::Roles(member.clone()));
+        extend(env, &data_key::Dao::Application(member.clone()));
+        extend(env, &data_key::Dao::Nonce(member.clone()));
+        extend(env, &data_key::Dao::OpenProposals(member.clone()));
+        extend(env, &data_key::Dao::LastProposal(member.clone()));
+        extend(env, &data_key::Dao::VoterSince(member));
+    }
+
+    dao_token::extend_ttl(env, members);
+}
+
+/// Extends the TTL of the entries of a proposal and of a page of its votes, objections and
+/// commitments.
+///
+/// # Arguments
+///
+/// - `proposal_id` - The id of the proposal.
+/// - `offset` - The index of the first vote, objection and commitment to extend.
+/// - `limit` - The maximum number of votes, objections and commitments to extend.
+pub fn extend_proposal(env: &Env, proposal_id: BytesN<32>, offset: u32, limit: u32) {
+    let summary_key = data_key::Proposal::Summary(proposal_id.clone());
+
+    // only the summary of a pruned proposal is left
+    if env.storage().persistent().has(&summary_key) {
+        extend_existing(env, &summary_key);
+        return;
+    }
+
+    let proposal_type = proposal::read_type(env, proposal_id.clone());
+
+    let id = proposal_id.clone();
+
+    for key in [
+        proposal::body_key(proposal_type, id.clone()),
+        data_key::Proposal::Metadata(id.clone()),
+        data_key::Proposal::Status(id.clone()),
+        data_key::Proposal::Tally(id.clone()),
+        data_key::Proposal::ObjectionCount(id.clone()),
+        data_key::Proposal::Vote(id.clone()),
+        data_key::Proposal::VoteCount(id.clone()),
+        data_key::Proposal::CommitmentCount(id.clone()),
+        data_key::Proposal::GraceEnd(id.clone()),
+        data_key::Proposal::Executed(id.clone()),
+        data_key::Proposal::ConsentEnd(id.clone()),
+        data_key::Proposal::Consented(id.clone()),
+        data_key::Proposal::FinalizedAt(id.clone()),
+        data_key::Proposal::Bond(id.clone()),
+        data_key::Proposal::Revisions(id.clone()),
+        data_key::Proposal::Signal(id.clone()),
+        data_key::Proposal::TemperatureCheckPassed(id.clone()),
+        data_key::Proposal::Dependents(id.clone()),
+        data_key::Proposal::BodyHash(proposal::read_body_hash(env, proposal_type, id.clone())),
+    ] {
+        extend(env, &key);
+    }
+
+    let end = offset
+        .saturating_add(limit)
+        .min(proposal::total_votes(env, id.clone()));
+
+    for index in offset..end {
+        extend(env, &data_key::Proposal::VoteAt(id.clone(), index));
+    }
+
+    for voter in proposal::read_votes(env, id.clone(), offset, limit) {
+        extend(env, &data_key::Proposal::Voter(id.clone(), voter.clone()));
+        extend(env, &data_key::Proposal::VoiceCredits(id.clone(), voter));
+    }
+
+    let end = offset
+        .saturating_add(limit)
+        .min(proposal::total_objections(env, id.clone()));
+
+    for index in offset..end {
+        extend(env, &data_key::Proposal::ObjectionAt(id.clone(), index));
+    }
+
+    for objector in proposal::read_objections(env, id.clone(), offset, limit) {
+        extend(env, &data_key::Proposal::Objector(id.clone(), objector));
+    }
+
+    let end = offset
+        .saturating_add(limit)
+        .min(proposal::total_commitments(env, id.clone()));
+
+    for index in offset..end {
+        extend(env, &data_key::Proposal::CommitmentAt(id.clone(), index));
+    }
+
+    for voter in proposal::read_committers(env, id.clone(), offset, limit) {
+        extend(env, &data_key::Proposal::Commitment(id.clone(), voter));
+    }
+
+    let candidates = proposal::read_candidates(env, id.clone(), proposal_type);
+
+    for candidate in candidates {
+        extend(
+            env,
+            &data_key::Proposal::CandidateConsent(id.clone(), candidate),
+        );
+    }
+}
+
+/// Stores the TTL thresholds of the DAO metadata in instance storage, which is loaded with
+/// the contract anyway, so extending an entry does not have to read the whole metadata.
+///
+/// # Arguments
+///
+/// - `metadata` - The DAO metadata.
+pub fn write_thresholds(env: &Env, metadata: &types::dao::Metadata) {
+    env.storage().instance().set(
+        &data_key::Dao::TtlThresholds,
+        &(metadata.ttl_threshold, metadata.ttl_extend_to),
+    );
+}
+
+fn extend_existing<K>(env: &Env, key: &K)
+where
+    K: IntoVal<Env, Val>,
+{
+    // the thresholds are absent before initialization
+    let thresholds: Option<(u32, u32)> =
+        env.storage().instance().get(&data_key::Dao::TtlThresholds);
+
+    if let Some((threshold, extend_to)) = thresholds {
+        env.storage()
+            .persistent()
+            .extend_ttl(key, threshold, extend_to);
+
+        // the contract instance is used by every call, so it is kept alive with the entries
+        env.storage().instance().extend_ttl(threshold, extend_to);
+    }
+}
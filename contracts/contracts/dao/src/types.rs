@@ -1,25 +1,90 @@
-use soroban_sdk::{contracttype, Address, BytesN, String};
+use soroban_sdk::{contracttype, Address, BytesN, String, Symbol, Vec};
 
 pub mod data_key {
     use super::*;
 
     #[contracttype]
     pub enum Dao {
+        /// Legacy storage of all members in one vector, see [`crate::member::migrate`].
         Members,
+        Member(Address),
+        MemberAt(u32),
+        MemberCount,
         TokenContractId,
         Metadata,
+        Guardians,
+        PausedUntil,
+        PauseVotes,
+        UnpauseVotes,
+        Roles(Address),
+        Profile(Address),
+        Application(Address),
+        Permission(proposal::Type, role::Action),
+        /// The proposal types and actions which have a permission set.
+        Permissions,
+        /// The number of members with voting power per set of roles they hold.
+        VoterRoles,
+        /// The time a member was granted their current voting power.
+        VoterSince(Address),
+        Nonce(Address),
+        /// The TTL thresholds of the metadata, kept in instance storage.
+        TtlThresholds,
+        OpenProposals(Address),
+        LastProposal(Address),
+        /// The total amount of an asset escrowed as proposal bonds.
+        BondEscrow(Address),
     }
 
     #[contracttype]
     pub enum Proposal {
+        /// Legacy storage of all votes in one vector, see [`crate::proposal::migrate_votes`].
         Vote(BytesN<32>),
+        Voter(BytesN<32>, Address),
+        VoteAt(BytesN<32>, u32),
+        VoteCount(BytesN<32>),
         AddMember(BytesN<32>),
         RemoveMember(BytesN<32>),
         GrantVotingPower(BytesN<32>),
         RevokeVotingPower(BytesN<32>),
         GeneralPurpose(BytesN<32>),
+        SetGuardians(BytesN<32>),
+        GrantRole(BytesN<32>),
+        RevokeRole(BytesN<32>),
+        SetPermission(BytesN<32>),
+        Spend(BytesN<32>),
+        BatchAddMember(BytesN<32>),
+        BatchRemoveMember(BytesN<32>),
+        BatchGrantVotingPower(BytesN<32>),
+        BatchRevokeVotingPower(BytesN<32>),
+        GraceEnd(BytesN<32>),
+        Executed(BytesN<32>),
+        ConsentEnd(BytesN<32>),
+        Consented(BytesN<32>),
+        CandidateConsent(BytesN<32>, Address),
         Metadata(BytesN<32>),
         Status(BytesN<32>),
+        VoiceCredits(BytesN<32>, Address),
+        Tally(BytesN<32>),
+        Commitment(BytesN<32>, Address),
+        /// The voter of the commitment at an index, kept after the commitment is revealed.
+        CommitmentAt(BytesN<32>, u32),
+        CommitmentCount(BytesN<32>),
+        /// The objector at an index of an optimistic proposal.
+        ObjectionAt(BytesN<32>, u32),
+        /// The index of the objection of a member to an optimistic proposal.
+        Objector(BytesN<32>, Address),
+        ObjectionCount(BytesN<32>),
+        Type(BytesN<32>),
+        FinalizedAt(BytesN<32>),
+        Summary(BytesN<32>),
+        /// The id of the proposal with the body of this hash.
+        BodyHash(BytesN<32>),
+        Bond(BytesN<32>),
+        Revisions(BytesN<32>),
+        Signal(BytesN<32>),
+        TemperatureCheckPassed(BytesN<32>),
+        /// The proposals which depend on this proposal.
+        Dependents(BytesN<32>),
     }
 }
 
@@ -31,28 +96,131 @@ pub mod dao {
         pub min_proposal_duration: u64,
         pub max_proposal_duration: u64,
         pub min_quorum_percentage: u32,
+        pub voting_mode: proposal::VotingMode,
+        /// Allows evaluating an active proposal once the remaining votes cannot change its outcome.
+        pub early_resolution: bool,
+        /// Percentage of members whose objections veto an optimistic proposal.
+        pub optimistic_veto_percentage: u32,
+        /// Seconds after which an emergency pause expires on its own.
+        pub max_pause_duration: u64,
+        /// Percentage of members that have to vote for an emergency pause or unpause without a guardian.
+        pub emergency_pause_percentage: u32,
+        /// Seconds after a pause ends during which only a member vote can pause the DAO again.
+        pub pause_cooldown: u64,
+        /// Token contracts held by the DAO which are paid out pro rata on ragequit.
+        pub treasury_assets: Vec<Address>,
+        /// Seconds after a spend proposal is accepted during which members may ragequit before it executes.
+        pub grace_period: u64,
+        /// Seconds after which a membership application nobody sponsored expires.
+        pub application_duration: u64,
+        /// Seconds the candidate of an accepted add member or grant voting power proposal has to consent.
+        pub consent_period: u64,
+        /// Members the DAO keeps at least. Removals and exits below it are rejected.
+        pub min_members: u32,
+        /// Members with voting power the DAO keeps at least. Removals, revocations and exits below it are rejected.
+        pub min_voting_members: u32,
+        /// Ledgers of remaining TTL below which a storage entry in use is extended.
+        pub ttl_threshold: u32,
+        /// Ledgers of TTL a storage entry in use is extended to. Must not exceed the network maximum TTL.
+        pub ttl_extend_to: u32,
+        /// Seconds after a proposal is finalized before its data can be pruned.
+        pub prune_delay: u64,
+        /// The bond a proposer deposits when creating a proposal.
+        pub proposal_bond: Bond,
+        /// Percentage of voting members that have to vote on or object to a rejected proposal
+        /// for its bond to be refunded.
+        pub bond_min_turnout_percentage: u32,
+        /// Proposals a member may have not started or active at the same time, zero for no limit.
+        pub max_open_proposals: u32,
+        /// Seconds a member has to wait after creating a proposal before creating the next one.
+        pub proposal_cooldown: u64,
+    }
+
+    /// A deposit escrowed by the DAO while a proposal is open.
+    #[contracttype]
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Bond {
+        /// No deposit is required.
+        Disabled,
+        /// The token contract and amount to deposit.
+        Asset(Address, i128),
     }
 }
 
 pub mod proposal {
     use super::*;
 
+    #[contracttype]
+    #[derive(Clone, Copy, Debug, PartialEq)]
     pub enum Type {
         AddMember,
         RemoveMember,
         GrantVotingPower,
         RevokeVotingPower,
         GeneralPurpose,
+        SetGuardians,
+        GrantRole,
+        RevokeRole,
+        SetPermission,
+        Spend,
+        BatchAddMember,
+        BatchRemoveMember,
+        BatchGrantVotingPower,
+        BatchRevokeVotingPower,
     }
 
     #[contracttype]
-    #[derive(Debug, PartialEq)]
+    #[derive(Clone, Debug, PartialEq)]
     pub enum Status {
         Active,
         Ended,
         Accepted,
         Rejected,
         NotStarted,
+        Vetoed,
+        /// Accepted, but the candidate did not consent within the consent period.
+        Lapsed,
+        /// Members signal support before the binding vote starts.
+        TemperatureCheck,
+        /// Not enough members signalled support, evaluating the proposal rejects it.
+        TemperatureCheckFailed,
+    }
+
+    /// How the votes on a proposal are counted.
+    #[contracttype]
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum VotingMode {
+        /// Uses the voting mode of the DAO. Only valid for proposals.
+        DaoDefault,
+        /// Every voter counts as one vote.
+        Simple,
+        /// Voters spend voice credits and count as the square root of the credits spent.
+        Quadratic(VoiceCredits),
+    }
+
+    /// The voice credit budget each member gets per proposal.
+    #[contracttype]
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum VoiceCredits {
+        /// Every member gets the same amount of credits.
+        Flat(u32),
+        /// Members get this amount of credits for every dao token they hold.
+        PerToken(u32),
+    }
+
+    /// How votes on a proposal are cast.
+    #[contracttype]
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Ballot {
+        /// Votes are published as soon as they are cast.
+        Open,
+        /// Voters commit to `sha256(choice || salt)` while the proposal is active and reveal
+        /// their choice during the given amount of seconds after it ends.
+        /// Unrevealed commitments are ignored.
+        Secret(u64),
+        /// The proposal passes when it ends unless the members objecting to it
+        /// exceed the optimistic veto percentage of the DAO.
+        Optimistic,
     }
 
     #[contracttype]
@@ -63,6 +231,71 @@ pub mod proposal {
         pub proposer: Address,
         pub start_time: u64,
         pub end_time: u64,
+        pub voting_mode: VotingMode,
+        pub ballot: Ballot,
+        pub temperature_check: TemperatureCheck,
+        /// Proposals which must be accepted and applied before this proposal can be evaluated
+        /// or executed. The candidates of a proposal with dependencies are checked when it is
+        /// evaluated, its minimum member counts when it is created.
+        pub dependencies: Vec<BytesN<32>>,
+    }
+
+    /// The non-binding signal vote a proposal runs before its binding vote.
+    #[contracttype]
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum TemperatureCheck {
+        Disabled,
+        /// Runs for the given seconds right before `start_time` and passes once the given
+        /// percentage of voting members signalled support.
+        Signal(u64, u32),
+    }
+
+    /// A change to a proposal which has not started yet.
+    #[contracttype]
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Amendment {
+        /// Replaces the name and description of the proposal.
+        Text(String, String),
+        /// Replaces the candidate of a proposal with a single candidate.
+        Candidate(Address),
+    }
+
+    /// A recorded amendment of a proposal.
+    #[contracttype]
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Revision {
+        /// The sha256 hash of the XDR encoded proposal body before the amendment.
+        pub previous_hash: BytesN<32>,
+        /// The sha256 hash of the XDR encoded proposal body after the amendment.
+        pub body_hash: BytesN<32>,
+        pub amended_at: u64,
+    }
+
+    /// The proposal limits of a member.
+    #[contracttype]
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct ProposerStatus {
+        /// The proposals of the member which have not started or are still active,
+        /// only tracked while the DAO limits open proposals.
+        pub open_proposals: u32,
+        /// The earliest time the member may create their next proposal.
+        pub next_proposal_at: u64,
+    }
+
+    /// The compact record of a proposal which is kept after its data has been pruned.
+    #[contracttype]
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Summary {
+        pub proposal_type: Type,
+        pub status: Status,
+        /// The votes for the proposal, weighted by its voting mode.
+        pub tally: u32,
+        pub votes: u32,
+        pub objections: u32,
+        /// The sha256 hash of the XDR encoded proposal body.
+        pub body_hash: BytesN<32>,
+        /// The time the proposal was finalized, zero if it is not finalized.
+        pub finalized_at: u64,
     }
 
     #[contracttype]
@@ -99,4 +332,132 @@ pub mod proposal {
         pub metadata: Metadata,
         pub actions: String,
     }
+
+    #[contracttype]
+    #[derive(Clone)]
+    pub struct SetGuardians {
+        pub metadata: Metadata,
+        /// The guardians replacing the current ones, empty to remove all guardians.
+        pub guardians: Vec<Address>,
+    }
+
+    #[contracttype]
+    #[derive(Clone)]
+    pub struct GrantRole {
+        pub metadata: Metadata,
+        pub candidate: Address,
+        pub role: Symbol,
+    }
+
+    #[contracttype]
+    #[derive(Clone)]
+    pub struct RevokeRole {
+        pub metadata: Metadata,
+        pub candidate: Address,
+        pub role: Symbol,
+    }
+
+    #[contracttype]
+    #[derive(Clone)]
+    pub struct SetPermission {
+        pub metadata: Metadata,
+        pub permission: role::Permission,
+    }
+
+    #[contracttype]
+    #[derive(Clone)]
+    pub struct BatchAddMember {
+        pub metadata: Metadata,
+        pub candidates: Vec<Address>,
+    }
+
+    #[contracttype]
+    #[derive(Clone)]
+    pub struct BatchRemoveMember {
+        pub metadata: Metadata,
+        pub candidates: Vec<Address>,
+    }
+
+    #[contracttype]
+    #[derive(Clone)]
+    pub struct BatchGrantVotingPower {
+        pub metadata: Metadata,
+        pub candidates: Vec<Address>,
+    }
+
+    #[contracttype]
+    #[derive(Clone)]
+    pub struct BatchRevokeVotingPower {
+        pub metadata: Metadata,
+        pub candidates: Vec<Address>,
+    }
+
+    #[contracttype]
+    #[derive(Clone)]
+    pub struct Spend {
+        pub metadata: Metadata,
+        /// The treasury asset to spend.
+        pub asset: Address,
+        pub recipient: Address,
+        pub amount: i128,
+    }
+}
+
+pub mod member {
+    use super::*;
+
+    /// How a member joined the DAO.
+    #[contracttype]
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Admission {
+        /// The member was one of the initial members of the DAO.
+        Initial,
+        /// The member was admitted by the add member proposal with this id.
+        Proposal(BytesN<32>),
+    }
+
+    #[contracttype]
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Member {
+        pub address: Address,
+        pub joined_at: u64,
+        pub admission: Admission,
+        /// The display name of the member, empty if not set.
+        pub name: String,
+        /// The sha256 hash of the profile document of the member, all zeros if not set.
+        pub profile_hash: BytesN<32>,
+    }
+
+    /// An application for membership by a non-member.
+    #[contracttype]
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Application {
+        pub applicant: Address,
+        pub statement: String,
+        pub submitted_at: u64,
+        pub expires_at: u64,
+    }
+}
+
+pub mod role {
+    use super::*;
+
+    /// Actions on proposals which can be restricted to roles.
+    #[contracttype]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum Action {
+        Propose,
+        Vote,
+        Evaluate,
+    }
+
+    /// The roles allowed to perform an action on a type of proposal.
+    /// Without any roles every member is allowed.
+    #[contracttype]
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Permission {
+        pub proposal_type: proposal::Type,
+        pub action: Action,
+        pub roles: Vec<Symbol>,
+    }
 }
@@ -1,4 +1,4 @@
-use crate::types::DataKey;
+use crate::types::{DataKey, INSTANCE_BUMP_AMOUNT, INSTANCE_LIFETIME_THRESHOLD};
 use soroban_sdk::{Address, Env};
 
 pub fn has_admin(env: &Env) -> bool {
@@ -12,3 +12,9 @@ pub fn read_admin(env: &Env) -> Address {
 pub fn write_admin(env: &Env, admin: Address) {
     env.storage().instance().set(&DataKey::Admin, &admin);
 }
+
+pub fn extend_instance(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
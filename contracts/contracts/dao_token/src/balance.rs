@@ -1,17 +1,54 @@
-use crate::types::DataKey;
+use crate::types::{DataKey, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD};
 use soroban_sdk::{Address, Env};
 
 pub fn write_balance(env: &Env, to: Address, amount: i128) {
+    write_total_supply(
+        env,
+        read_total_supply(env) - read_balance(env, to.clone()) + amount,
+    );
+
+    let key = DataKey::Balance(to);
+    env.storage().persistent().set(&key, &amount);
+    extend_balance(env, &key);
+}
+
+pub fn read_total_supply(env: &Env) -> i128 {
+    read(env, &DataKey::TotalSupply)
+}
+
+pub fn write_total_supply(env: &Env, total_supply: i128) {
     env.storage()
         .persistent()
-        .set(&DataKey::Balance(to), &amount);
+        .set(&DataKey::TotalSupply, &total_supply);
+    extend_balance(env, &DataKey::TotalSupply);
 }
 
 pub fn read_balance(env: &Env, from: Address) -> i128 {
+    read(env, &DataKey::Balance(from))
+}
+
+/// Extends the TTL of the balance of `id` and of the total supply, if they exist.
+pub fn extend_balance_ttl(env: &Env, id: Address) {
+    for key in [DataKey::Balance(id), DataKey::TotalSupply] {
+        if env.storage().persistent().has(&key) {
+            extend_balance(env, &key);
+        }
+    }
+}
+
+fn read(env: &Env, key: &DataKey) -> i128 {
+    if let Some(amount) = env.storage().persistent().get(key) {
+        extend_balance(env, key);
+        amount
+    } else {
+        0
+    }
+}
+
+fn extend_balance(env: &Env, key: &DataKey) {
     env.storage()
         .persistent()
-        .get(&DataKey::Balance(from))
-        .unwrap_or(0)
+        .extend_ttl(key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
 }
 
 pub fn burn_balance(env: &Env, from: Address, amount: i128) {
@@ -1,5 +1,5 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, Address, Env, String};
+use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
 
 mod metadata;
 pub use metadata::*;
@@ -36,6 +36,7 @@ impl TokenContract {
         write_metadata(&env, Metadata { name, symbol });
 
         write_admin(&env, admin);
+        extend_instance(&env);
     }
 
     /// Returns the name for this token.
@@ -56,10 +57,18 @@ impl TokenContract {
     /// address has no existing balance, returns 0.
     pub fn balance(env: Env, id: Address) -> i128 {
         read_admin(&env).require_auth();
+        extend_instance(&env);
 
         read_balance(&env, id)
     }
 
+    /// Returns the total amount of tokens held by all addresses.
+    pub fn total_supply(env: Env) -> i128 {
+        extend_instance(&env);
+
+        read_total_supply(&env)
+    }
+
     /// Mint `amount` to `to`.
     ///
     /// # Arguments
@@ -69,6 +78,7 @@ impl TokenContract {
     pub fn mint(env: Env, to: Address, amount: i128) {
         check_non_negative(amount);
         read_admin(&env).require_auth();
+        extend_instance(&env);
 
         write_balance(&env, to.clone(), amount);
         Event::mint(&env, to, amount);
@@ -90,12 +100,40 @@ impl TokenContract {
     pub fn burn(env: Env, from: Address, amount: i128) {
         // from.require_auth();
         read_admin(&env).require_auth();
+        extend_instance(&env);
 
         check_non_negative(amount);
 
         burn_balance(&env, from.clone(), amount);
         Event::burn(&env, from, amount);
     }
+
+    /// Adds balances minted before the total supply was tracked to the total supply.
+    ///
+    /// # Arguments
+    ///
+    /// - `amount` - The amount of tokens held in untracked balances.
+    pub fn migrate_supply(env: Env, amount: i128) {
+        check_non_negative(amount);
+        read_admin(&env).require_auth();
+        extend_instance(&env);
+
+        write_total_supply(&env, read_total_supply(&env) + amount);
+    }
+
+    /// Extends the TTL of the contract instance and of the balances of `ids`
+    /// so they are not archived. Anyone may call it.
+    ///
+    /// # Arguments
+    ///
+    /// - `ids` - The addresses whose balances are extended.
+    pub fn extend_ttl(env: Env, ids: Vec<Address>) {
+        extend_instance(&env);
+
+        for id in ids {
+            extend_balance_ttl(&env, id);
+        }
+    }
 }
 
 fn check_non_negative(amount: i128) {
@@ -5,8 +5,8 @@ extern crate std;
 use std::println;
 
 use super::*;
-use soroban_sdk::testutils::{Address as AddressTest, Logs};
-use soroban_sdk::{Address, Env, String};
+use soroban_sdk::testutils::{Address as AddressTest, Ledger, Logs};
+use soroban_sdk::{Address, Env, String, Vec};
 
 fn create_token<'a>(env: &'a Env, admin: &'a Address) -> TokenContractClient<'a> {
     let name = String::from_str(&env, "MyToken");
@@ -129,3 +129,65 @@ fn burn_insufficient_balance() {
 
     token.burn(&from, &300_i128);
 }
+
+#[test]
+fn total_supply() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+
+    let token = create_token(&env, &admin);
+
+    token.mint(&a, &100_i128);
+    token.mint(&b, &50_i128);
+
+    assert_eq!(token.total_supply(), 150_i128);
+
+    token.burn(&a, &60_i128);
+
+    assert_eq!(token.total_supply(), 90_i128);
+}
+
+#[test]
+fn migrate_supply() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let a = Address::generate(&env);
+
+    let token = create_token(&env, &admin);
+
+    token.mint(&a, &100_i128);
+    token.migrate_supply(&50_i128);
+
+    assert_eq!(token.total_supply(), 150_i128);
+}
+
+#[test]
+fn extend_ttl() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let a = Address::generate(&env);
+
+    let token = create_token(&env, &admin);
+
+    token.mint(&a, &100_i128);
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number += 20 * types::DAY_IN_LEDGERS;
+    });
+
+    token.extend_ttl(&Vec::from_array(&env, [a.clone()]));
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number += 20 * types::DAY_IN_LEDGERS;
+    });
+
+    assert_eq!(token.balance(&a), 100_i128);
+}
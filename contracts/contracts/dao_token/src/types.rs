@@ -1,10 +1,19 @@
 use soroban_sdk::{contracttype, Address, String};
 
+pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
+
+pub(crate) const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub(crate) const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+pub(crate) const BALANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub(crate) const BALANCE_LIFETIME_THRESHOLD: u32 = BALANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
 #[contracttype]
 pub enum DataKey {
     Initialized,
     Admin,
     Balance(Address),
+    TotalSupply,
 }
 
 #[contracttype]
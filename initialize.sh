@@ -7,4 +7,4 @@ soroban contract invoke \
     -- \
     initialize \
     --initial_members "[('GCCCK5QP24RVHAFFJNBPF6UBQ3RBLG3Y4U2UDNKKXZPQSNLVOC4JOUFS'),('GDOQ5IC2STP43BHTMTHUTDY3OUEAZUEY5IKRJUTXNVZUVIRPAPBWSRAY'),('GCENNJQ22BPJRE4UBFRMGOJGN2IMWZSUMSW76H2JGPQCS52WW7R3XOJS')]" \
-    --metadata "{min_proposal_duration: 3600, max_proposal_duration: 604800, min_quorum_percentage: 50}"
\ No newline at end of file
+    --metadata "{min_proposal_duration: 3600, max_proposal_duration: 604800, min_quorum_percentage: 50, voting_mode: \"Simple\", early_resolution: false, optimistic_veto_percentage: 50, max_pause_duration: 86400, emergency_pause_percentage: 66, pause_cooldown: 86400, treasury_assets: [], grace_period: 86400, application_duration: 604800, consent_period: 86400, min_members: 3, min_voting_members: 2, ttl_threshold: 120960, ttl_extend_to: 518400, prune_delay: 604800, proposal_bond: \"Disabled\", bond_min_turnout_percentage: 20, max_open_proposals: 3, proposal_cooldown: 3600}"
\ No newline at end of file